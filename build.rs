@@ -0,0 +1,73 @@
+//! Generates a test for every part of every example in `src/solutions/*/examples`, see
+//! `tests/examples.rs`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/solutions");
+
+    let mut tests = String::new();
+
+    let mut puzzles: Vec<_> = fs::read_dir("src/solutions")
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.join("examples").is_dir())
+        .collect();
+    puzzles.sort();
+
+    for puzzle in puzzles {
+        let name = puzzle.file_name().unwrap().to_str().unwrap();
+        let year: u16 = name[1..5].parse().unwrap();
+        let day: u8 = name[6..].parse().unwrap();
+
+        writeln!(tests, "mod {} {{", name).unwrap();
+
+        for (example, parts) in examples(&puzzle.join("examples")) {
+            writeln!(tests, "    mod example_{} {{", example.replace('-', "_")).unwrap();
+            for part in parts {
+                writeln!(
+                    tests,
+                    "        #[test] fn part{part}() {{ crate::check({year}, {day}, {example:?}, {part}); }}"
+                )
+                .unwrap();
+            }
+            writeln!(tests, "    }}").unwrap();
+        }
+
+        writeln!(tests, "}}").unwrap();
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, tests).unwrap();
+}
+
+/// Examples in the directory, with the parts that have a known answer.
+fn examples(dir: &Path) -> Vec<(String, Vec<u8>)> {
+    let mut examples = Vec::new();
+
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let file_name = path.file_name().unwrap().to_str().unwrap();
+
+        let Some(example) = file_name.strip_suffix(".answers.txt") else {
+            continue;
+        };
+
+        let answers = fs::read_to_string(&path).unwrap();
+        let parts = (1..=2)
+            .filter(|part| {
+                answers
+                    .lines()
+                    .any(|l| l.trim().starts_with(&format!("Part {}:", part)))
+            })
+            .collect();
+
+        examples.push((example.to_string(), parts));
+    }
+
+    examples.sort();
+    examples
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::input;
+use crate::registry::{Answers, Puzzle};
+
+/// Path of the known answers for the given input file: `answers.txt` next to an in-tree
+/// `input.txt`, and `DD.answers.txt` next to `DD.txt` in the inputs directory.
+pub fn path_for(input: &Path) -> PathBuf {
+    match input.file_stem().and_then(|s| s.to_str()) {
+        Some("input") | None => input.with_file_name("answers.txt"),
+        Some(stem) => input.with_file_name(format!("{}.answers.txt", stem)),
+    }
+}
+
+/// Load the known answers for the given input file, if there are any.
+pub fn load(input: &Path) -> Result<Option<Answers>> {
+    let path = path_for(input);
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    parse(&fs::read_to_string(&path)?)
+        .map(Some)
+        .map_err(|e| anyhow!("Invalid answers in {}: {}", path.display(), e))
+}
+
+/// Parse answers in the same format as `aoc run` prints them, e.g. `Part 1: 42`.
+pub fn parse(s: &str) -> Result<Answers> {
+    let mut answers = Answers::default();
+
+    for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        let (part, answer) = line
+            .split_once(": ")
+            .ok_or(anyhow!("Unable to match {}", line))?;
+
+        let answer = Some(answer.to_string());
+        match part {
+            "Part 1" => answers.part1 = answer,
+            "Part 2" => answers.part2 = answer,
+            _ => bail!("Unknown part {}", part),
+        }
+    }
+
+    Ok(answers)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// The part is solved, but the correct answer is not known.
+    Missing,
+    /// The part is not solved and there is no known answer.
+    Unsolved,
+}
+
+impl Verdict {
+    pub fn check(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match (expected, actual) {
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), actual) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual: actual.map(str::to_string),
+            },
+            (None, Some(_)) => Verdict::Missing,
+            (None, None) => Verdict::Unsolved,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail { .. } => "fail",
+            Verdict::Missing => "missing",
+            Verdict::Unsolved => "-",
+        };
+        f.pad(s)
+    }
+}
+
+/// Run the puzzle on its default input and compare both parts to the known answers.
+pub fn verify(puzzle: &Puzzle) -> Result<[Verdict; 2]> {
+    let path = input::locate(puzzle)?;
+    let expected = load(&path)?.unwrap_or_default();
+    let actual = puzzle.run(&fs::read_to_string(&path)?)?;
+
+    Ok([
+        Verdict::check(expected.part1.as_deref(), actual.part1.as_deref()),
+        Verdict::check(expected.part2.as_deref(), actual.part2.as_deref()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_for() {
+        assert_eq!(
+            path_for(Path::new("src/solutions/y2015d07/input.txt")),
+            Path::new("src/solutions/y2015d07/answers.txt")
+        );
+        assert_eq!(
+            path_for(Path::new("/inputs/2015/07.txt")),
+            Path::new("/inputs/2015/07.answers.txt")
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("Part 1: 3176\nPart 2: 14710\n").unwrap(),
+            Answers::new(3176, 14710)
+        );
+        assert_eq!(parse("Part 2: abc\n").unwrap(), Answers::part2("abc"));
+        assert_eq!(parse("").unwrap(), Answers::default());
+        assert!(parse("3176").is_err());
+        assert!(parse("Part 3: 1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Verdict::check(Some("1"), Some("1")), Verdict::Pass);
+        assert_eq!(
+            Verdict::check(Some("1"), Some("2")),
+            Verdict::Fail {
+                expected: "1".into(),
+                actual: Some("2".into())
+            }
+        );
+        assert_eq!(
+            Verdict::check(Some("1"), None),
+            Verdict::Fail {
+                expected: "1".into(),
+                actual: None
+            }
+        );
+        assert_eq!(Verdict::check(None, Some("2")), Verdict::Missing);
+        assert_eq!(Verdict::check(None, None), Verdict::Unsolved);
+    }
+
+    #[test]
+    fn test_known_answers_are_valid() {
+        for puzzle in crate::registry::PUZZLES {
+            load(&input::in_tree_path(puzzle)).unwrap();
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Map, Value};
+
+/// Timings of repeated runs of a single stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Self> {
+        samples.sort();
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: samples[samples.len() / 2],
+            max: *samples.last()?,
+        })
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "max_ns": self.max.as_nanos() as u64,
+        })
+    }
+
+    fn from_json(value: &Value) -> Result<Self> {
+        let field = |name| {
+            value[name]
+                .as_u64()
+                .map(Duration::from_nanos)
+                .ok_or(anyhow!("Missing {} in {}", name, value))
+        };
+
+        Ok(Stats {
+            min: field("min_ns")?,
+            median: field("median_ns")?,
+            max: field("max_ns")?,
+        })
+    }
+}
+
+/// Run `f` the given number of times, measuring each run separately.
+pub fn measure<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let mut samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let instant = Instant::now();
+        black_box(f()?);
+        samples.push(instant.elapsed());
+    }
+
+    Stats::from_samples(&mut samples).ok_or(anyhow!("At least one iteration is needed"))
+}
+
+/// Stats by puzzle name and then by stage name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline(pub BTreeMap<String, BTreeMap<String, Stats>>);
+
+impl Baseline {
+    pub fn get(&self, puzzle: &str, stage: &str) -> Option<&Stats> {
+        self.0.get(puzzle)?.get(stage)
+    }
+
+    pub fn insert(&mut self, puzzle: &str, stage: &str, stats: Stats) {
+        self.0
+            .entry(puzzle.to_string())
+            .or_default()
+            .insert(stage.to_string(), stats);
+    }
+
+    /// Load the baseline, which is empty if the file doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let value: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+        let puzzles = value
+            .as_object()
+            .ok_or(anyhow!("Expected an object in {}", path.display()))?;
+
+        let mut baseline = Self::default();
+        for (puzzle, stages) in puzzles {
+            let stages = stages
+                .as_object()
+                .ok_or(anyhow!("Expected an object for {}", puzzle))?;
+
+            for (stage, stats) in stages {
+                baseline.insert(puzzle, stage, Stats::from_json(stats)?);
+            }
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let value: Map<String, Value> = self
+            .0
+            .iter()
+            .map(|(puzzle, stages)| {
+                let stages = stages
+                    .iter()
+                    .map(|(stage, stats)| (stage.clone(), stats.to_json()))
+                    .collect();
+                (puzzle.clone(), Value::Object(stages))
+            })
+            .collect();
+
+        fs::write(path, serde_json::to_string_pretty(&value)? + "\n")?;
+        Ok(())
+    }
+}
+
+/// Relative change of the median compared to the previous measurement, e.g. `0.1` for 10% slower.
+pub fn change(previous: &Stats, current: &Stats) -> f64 {
+    let previous = previous.median.as_secs_f64();
+    let current = current.median.as_secs_f64();
+
+    if previous == 0.0 {
+        0.0
+    } else {
+        (current - previous) / previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&mut [ms(5), ms(1), ms(3), ms(2), ms(4)]).unwrap();
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+
+        assert_eq!(Stats::from_samples(&mut []), None);
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut runs = 0;
+        measure(3, || {
+            runs += 1;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(runs, 3);
+
+        assert!(measure(0, || Ok(())).is_err());
+        assert!(measure(3, || -> Result<()> { Err(anyhow!("failed")) }).is_err());
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = Baseline::default();
+        let stats = Stats {
+            min: ms(1),
+            median: ms(2),
+            max: ms(3),
+        };
+        baseline.insert("y2015d01", "run", stats);
+
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}.json", std::process::id()));
+        baseline.save(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, baseline);
+        assert_eq!(loaded.get("y2015d01", "run"), Some(&stats));
+        assert_eq!(Baseline::load(&path).unwrap(), Baseline::default());
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = |median| Stats {
+            min: ms(1),
+            median: ms(median),
+            max: ms(100),
+        };
+
+        assert!((change(&stats(10), &stats(15)) - 0.5).abs() < 1e-9);
+        assert!((change(&stats(10), &stats(5)) + 0.5).abs() < 1e-9);
+        assert_eq!(change(&stats(0), &stats(5)), 0.0);
+    }
+}
@@ -0,0 +1,125 @@
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+/// Error of work that was cancelled or ran out of its time budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+/// Cancellation flag that can be shared between threads, with an optional deadline.
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Token {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Token that is cancelled together with this one, and also expires after `budget`.
+    pub fn with_budget(&self, budget: Duration) -> Self {
+        let deadline = Instant::now() + budget;
+        Token {
+            cancelled: self.cancelled.clone(),
+            deadline: Some(self.deadline.map_or(deadline, |d| d.min(deadline))),
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with `token` as the current token of the thread, see [`check`].
+pub fn scope<T>(token: Token, f: impl FnOnce() -> T) -> T {
+    /// Restores the previous token, even when `f` panics.
+    struct Restore(Option<Token>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.set(self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.replace(Some(token)));
+    f()
+}
+
+/// The token of the innermost [`scope`], if any.
+pub fn current() -> Option<Token> {
+    CURRENT.with_borrow(Clone::clone)
+}
+
+/// Fail with [`Cancelled`] once the current token is cancelled or expired. Long-running loops
+/// call this regularly, outside of a [`scope`] it never fails.
+pub fn check() -> Result<()> {
+    if CURRENT.with_borrow(|token| token.as_ref().is_some_and(Token::is_cancelled)) {
+        return Err(Cancelled.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_scope() {
+        assert!(check().is_ok());
+
+        let token = Token::new();
+        scope(token.clone(), || {
+            assert!(check().is_ok());
+            token.cancel();
+            assert!(check().unwrap_err().is::<Cancelled>());
+        });
+
+        // The token is only current inside the scope.
+        assert!(check().is_ok());
+        assert!(current().is_none());
+    }
+
+    #[test]
+    fn test_budget() {
+        let token = Token::new();
+        let budgeted = token.with_budget(Duration::from_millis(20));
+        assert!(!budgeted.is_cancelled());
+
+        thread::sleep(Duration::from_millis(30));
+        assert!(budgeted.is_cancelled());
+        assert!(!token.is_cancelled());
+
+        // Cancelling the parent cancels the children as well.
+        let budgeted = token.with_budget(Duration::from_secs(60));
+        token.cancel();
+        assert!(budgeted.is_cancelled());
+    }
+}
@@ -0,0 +1,207 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::input::{self, INPUTS_DIR_VAR};
+use crate::log;
+use crate::registry;
+
+/// Environment variable with the value of the `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding [`DEFAULT_BASE_URL`], e.g. for a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Minimum time between two requests, to go easy on the server.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    /// The input was already in the inputs directory, nothing was requested.
+    Cached(PathBuf),
+}
+
+/// Downloads puzzle inputs into the inputs directory, at most one request per interval.
+#[derive(Debug)]
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    inputs_dir: PathBuf,
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, inputs_dir: &Path) -> Self {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            inputs_dir: inputs_dir.to_path_buf(),
+            interval: DEFAULT_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    /// Configure the fetcher from [`SESSION_VAR`], [`INPUTS_DIR_VAR`] and [`BASE_URL_VAR`].
+    pub fn from_env() -> Result<Self> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|s| !s.is_empty())
+            .ok_or(anyhow!(
+                "Set {} to the value of the session cookie",
+                SESSION_VAR
+            ))?;
+        let inputs_dir = env::var_os(INPUTS_DIR_VAR).ok_or(anyhow!(
+            "Set {} to the directory for the inputs",
+            INPUTS_DIR_VAR
+        ))?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        Ok(Fetcher::new(&base_url, &session, Path::new(&inputs_dir)))
+    }
+
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Fetcher { interval, ..self }
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// Download the input unless it is cached already. The puzzle doesn't need a solution yet.
+    pub fn fetch(&mut self, year: u16, day: u8) -> Result<Fetched> {
+        registry::check_day(year, day)?;
+
+        let path = input::inputs_dir_path(&self.inputs_dir, year, day);
+        if path.is_file() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.download(&self.url(year, day))?;
+        if input.trim().is_empty() {
+            bail!("Empty input for {} day {}", year, day);
+        }
+
+        // Write to a temporary file first, so that an interrupted write is never cached.
+        let dir = path.parent().unwrap();
+        fs::create_dir_all(dir)?;
+        let partial = path.with_extension("txt.partial");
+        fs::write(&partial, input)
+            .with_context(|| format!("Unable to write {}", partial.display()))?;
+        fs::rename(&partial, &path)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    fn download(&mut self, url: &str) -> Result<String> {
+        if let Some(last) = self.last_request {
+            let wait = self.interval.saturating_sub(last.elapsed());
+            if !wait.is_zero() {
+                log::info!("Waiting {:.1?} before the next request", wait);
+                thread::sleep(wait);
+            }
+        }
+        self.last_request = Some(Instant::now());
+
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "aoc input fetcher")
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => anyhow!("Unable to fetch {}: {}", url, status),
+                // Transport errors name the URL already.
+                e => anyhow!("Unable to fetch {}", e),
+            })?;
+
+        Ok(response.into_string()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    use super::*;
+
+    /// Serve `responses` in order on a local port, reporting the path and cookie of every
+    /// request.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut lines = BufReader::new(stream.try_clone().unwrap()).lines();
+
+                let request_line = lines.next().unwrap().unwrap();
+                let path = request_line.split(' ').nth(1).unwrap().to_string();
+                let mut cookie = String::new();
+                for line in lines.map(Result::unwrap).take_while(|l| !l.is_empty()) {
+                    if let Some(value) = line.strip_prefix("Cookie: ") {
+                        cookie = value.to_string();
+                    }
+                }
+                sender.send((path, cookie)).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, receiver)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let inputs_dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let (url, requests) = serve(vec![(200, "1 2 3\n"), (404, ""), (200, "4 5 6\n")]);
+        let interval = Duration::from_millis(200);
+        let mut fetcher = Fetcher::new(&url, "secret", &inputs_dir).with_interval(interval);
+
+        let start = Instant::now();
+        let path = inputs_dir.join("2015/07.txt");
+        assert_eq!(
+            fetcher.fetch(2015, 7).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            ("/2015/day/7/input".into(), "session=secret".into())
+        );
+
+        // Cached inputs are never requested again.
+        assert_eq!(fetcher.fetch(2015, 7).unwrap(), Fetched::Cached(path));
+
+        let error = fetcher.fetch(2016, 1).unwrap_err();
+        assert!(error.to_string().ends_with("/2016/day/1/input: 404"));
+        assert!(!inputs_dir.join("2016/01.txt").exists());
+
+        // Inputs can be fetched before the puzzle has a solution, but only for real puzzles.
+        assert!(registry::find(2016, 2).is_none());
+        assert!(fetcher.fetch(2016, 2).is_ok());
+        assert!(inputs_dir.join("2016/02.txt").is_file());
+        assert!(fetcher.fetch(2016, 26).is_err());
+        assert!(start.elapsed() >= interval * 2);
+        assert_eq!(requests.iter().count(), 2);
+
+        fs::remove_dir_all(&inputs_dir).unwrap();
+    }
+}
@@ -0,0 +1,293 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use anyhow::Result;
+
+use crate::parse::{self, ParseError};
+
+/// Position in a grid as `(row, column)`.
+pub type Pos = (usize, usize);
+
+/// Offsets of the orthogonal neighbours.
+const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours.
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular grid stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parse a char map, one row per line, mapping every char to a tile. Errors point at the
+    /// offending char.
+    pub fn parse(s: &str, mut tile: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (i, line) in s.lines().enumerate() {
+            let row_start = cells.len();
+            for (j, c) in line.chars().enumerate() {
+                let t = tile(c).map_err(|e| parse::in_line(e, i + 1, line).with_column(j + 1))?;
+                cells.push(t);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let message = format!("Expected a row of width {}, got {}", width, row_width);
+                    return Err(ParseError::at_line(i + 1, line, message).into());
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, col): Pos) -> Option<&T> {
+        if row < self.height && col < self.width {
+            Some(&self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (row, col): Pos) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            Some(&mut self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// Convert signed coordinates to a position, if they are inside the grid.
+    pub fn checked_pos(&self, row: i64, col: i64) -> Option<Pos> {
+        let pos = (usize::try_from(row).ok()?, usize::try_from(col).ok()?);
+        self.get(pos).map(|_| pos)
+    }
+
+    /// Position moved by the given offset, if it stays inside the grid.
+    pub fn offset(&self, (row, col): Pos, (drow, dcol): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(drow)?, col.checked_add_signed(dcol)?);
+        self.get(pos).map(|_| pos)
+    }
+
+    /// Orthogonal neighbours inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// Orthogonal and diagonal neighbours inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy for grids without columns.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Position of the first tile matching the predicate, in row-major order.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, tile)| predicate(tile).then_some(pos))
+    }
+
+    /// Positions of all the tiles matching the predicate, in row-major order.
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        self.iter()
+            .filter_map(move |(pos, tile)| predicate(tile).then_some(pos))
+    }
+
+    /// Apply `f` to every tile.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Swap rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |(row, col)| (col, row))
+    }
+
+    /// Rotate by 90 degrees clockwise.
+    pub fn rotate(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearrange(height, self.width, |(row, col)| (height - 1 - col, row))
+    }
+
+    /// Build a grid of the given size, taking each tile from the position given by `source`.
+    fn rearrange(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[source((i / width, i % width))].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {:?} is outside of the grid", pos))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for tile in row {
+                write!(f, "{}", tile)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+
+    use super::*;
+
+    fn grid(s: &str) -> Grid<char> {
+        Grid::parse(s, Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let g = grid("ab\ncd\nef\n");
+        assert_eq!((g.width(), g.height()), (2, 3));
+        assert_eq!(g[(2, 1)], 'f');
+        assert_eq!(g.get((0, 2)), None);
+        assert_eq!(g.get((3, 0)), None);
+
+        assert!(Grid::parse("ab\nc\n", Ok).is_err());
+
+        let error = Grid::parse("aa\na?\n", |c| match c {
+            'a' => Ok(true),
+            _ => bail!("Invalid tile {}", c),
+        })
+        .unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid("abc\ndef\nghi\n");
+        let chars = |ps: Vec<Pos>| ps.into_iter().map(|p| g[p]).collect::<String>();
+
+        assert_eq!(chars(g.neighbours4((0, 0)).collect()), "bd");
+        assert_eq!(chars(g.neighbours4((1, 1)).collect()), "bdfh");
+        assert_eq!(chars(g.neighbours8((0, 0)).collect()), "bde");
+        assert_eq!(chars(g.neighbours8((1, 1)).collect()), "abcdfghi");
+        assert_eq!(chars(g.neighbours8((2, 2)).collect()), "efh");
+    }
+
+    #[test]
+    fn test_find() {
+        let g = grid("#.#\n.#.\n");
+        assert_eq!(g.find(|&c| c == '.'), Some((0, 1)));
+        assert_eq!(g.find(|&c| c == '@'), None);
+        assert_eq!(
+            g.find_all(|&c| c == '#').collect::<Vec<_>>(),
+            [(0, 0), (0, 2), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn test_checked_pos() {
+        let g = grid("ab\ncd\n");
+        assert_eq!(g.checked_pos(1, 0), Some((1, 0)));
+        assert_eq!(g.checked_pos(-1, 0), None);
+        assert_eq!(g.checked_pos(0, 2), None);
+    }
+
+    #[test]
+    fn test_transpose_and_rotate() {
+        let g = grid("abc\ndef\n");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(g.rotate().to_string(), "da\neb\nfc\n");
+        assert_eq!(g.rotate().rotate().rotate().rotate(), g);
+    }
+}
@@ -0,0 +1,138 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+
+use crate::registry::Puzzle;
+
+/// Environment variable pointing to a directory with inputs in a `YYYY/DD.txt` layout.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS";
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Source {
+    /// Explicitly given file.
+    Path(PathBuf),
+    Stdin,
+    /// The inputs directory if [`INPUTS_DIR_VAR`] is set, otherwise the in-tree input.
+    #[default]
+    Default,
+}
+
+impl Source {
+    /// Parse the value of the `--input` flag, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(PathBuf::from(path)),
+        }
+    }
+}
+
+pub fn load(source: &Source, puzzle: &Puzzle) -> Result<String> {
+    match source {
+        Source::Path(path) => fs::read_to_string(path)
+            .with_context(|| format!("Unable to read input {}", path.display())),
+        Source::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Source::Default => {
+            let path = locate(puzzle)?;
+            fs::read_to_string(&path)
+                .with_context(|| format!("Unable to read input {}", path.display()))
+        }
+    }
+}
+
+/// Find the first existing input file out of the [`candidates`].
+pub fn locate(puzzle: &Puzzle) -> Result<PathBuf> {
+    let inputs_dir = env::var_os(INPUTS_DIR_VAR).map(PathBuf::from);
+    let candidates = candidates(inputs_dir.as_deref(), puzzle);
+
+    if let Some(path) = candidates.iter().find(|path| path.is_file()) {
+        return Ok(path.clone());
+    }
+
+    bail!(
+        "No input for {} day {}, tried: {}",
+        puzzle.year,
+        puzzle.day,
+        candidates
+            .iter()
+            .map(|p| p.display().to_string())
+            .join(", ")
+    )
+}
+
+/// Input paths to try in order, when no explicit source is given.
+pub fn candidates(inputs_dir: Option<&Path>, puzzle: &Puzzle) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+
+    if let Some(inputs_dir) = inputs_dir {
+        candidates.push(inputs_dir_path(inputs_dir, puzzle.year, puzzle.day));
+    }
+    candidates.push(in_tree_path(puzzle));
+
+    candidates
+}
+
+pub fn inputs_dir_path(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+/// The input file next to the solution, e.g. `src/solutions/y2015d07/input.txt`.
+pub fn in_tree_path(puzzle: &Puzzle) -> PathBuf {
+    puzzle_dir(puzzle).join("input.txt")
+}
+
+/// Directory of the solution module, independent of the current working directory.
+pub fn puzzle_dir(puzzle: &Puzzle) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/solutions")
+        .join(puzzle.name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("in.txt"),
+            Source::Path(PathBuf::from("in.txt"))
+        );
+    }
+
+    #[test]
+    fn test_candidates() {
+        let puzzle = registry::find(2015, 7).unwrap();
+
+        let candidates = candidates(Some(Path::new("/inputs")), puzzle);
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0], Path::new("/inputs/2015/07.txt"));
+        assert!(candidates[1].ends_with("src/solutions/y2015d07/input.txt"));
+
+        assert_eq!(super::candidates(None, puzzle), vec![in_tree_path(puzzle)]);
+    }
+
+    #[test]
+    fn test_load_explicit_path() {
+        let puzzle = registry::find(2015, 7).unwrap();
+
+        let input = load(&Source::Path(in_tree_path(puzzle)), puzzle).unwrap();
+        assert!(input.starts_with("NOT dq -> dr"));
+
+        let err = load(&Source::Path(PathBuf::from("/nonexistent.txt")), puzzle).unwrap_err();
+        assert!(err.to_string().contains("/nonexistent.txt"));
+    }
+}
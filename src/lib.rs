@@ -0,0 +1,18 @@
+pub mod answers;
+pub mod bench;
+pub mod cancel;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod math;
+pub mod memory;
+pub mod output;
+pub mod parse;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod solutions;
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Verbosity of the diagnostics on stderr, answers on stdout are never affected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Only errors, `-q`.
+    Error = 0,
+    #[default]
+    Info = 1,
+    /// Everything the solvers report about their progress, `-v`.
+    Debug = 2,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Error => write!(f, "error"),
+            Level::Info => write!(f, "info"),
+            Level::Debug => write!(f, "debug"),
+        }
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Write a message to stderr if the level is enabled, use [`debug!`] and [`info!`] instead.
+pub fn log(level: Level, args: fmt::Arguments) {
+    if enabled(level) {
+        eprintln!("{}: {}", level, args);
+    }
+}
+
+/// Report a diagnostic that is only shown with `-v`, e.g. `debug!("Start: {:?}", start)`.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::log($crate::log::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+/// Report a diagnostic that is shown unless `-q` is given.
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            $crate::log::log($crate::log::Level::Info, format_args!($($arg)*));
+        }
+    };
+}
+
+pub(crate) use {debug, info};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level() {
+        assert!(Level::Error < Level::Info && Level::Info < Level::Debug);
+        assert_eq!(Level::Error.to_string(), "error");
+
+        // The default level shows everything but the debug messages.
+        assert!(enabled(Level::Info));
+        assert!(!enabled(Level::Debug));
+    }
+}
@@ -0,0 +1,416 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+
+use aoc::answers::{self, Verdict};
+use aoc::bench::{self, Baseline, Stats};
+use aoc::fetch::{Fetched, Fetcher};
+use aoc::input::{self, Source};
+use aoc::log::{self, Level};
+use aoc::memory::{self, Usage};
+use aoc::output::{self, Format};
+use aoc::parse::ParseError;
+use aoc::registry::{self, Puzzle};
+use aoc::report;
+use aoc::runner::{self, Status};
+use aoc::scaffold;
+
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+const USAGE: &str = "Usage:
+  aoc run all | <year> [<day>] [--input <path>|-] [--output text|json]
+      [--budget <seconds>] [--parallel [--timeout <seconds>]]
+  aoc verify all | <year> [<day>]
+  aoc bench all | <year> [<day>] [--input <path>|-] [--iterations <n>] [--baseline <path>]
+      [--threshold <percent>] [--save-baseline] [--memory]
+  aoc new <year> <day>
+  aoc fetch all | <year> [<day>]
+  aoc report [--baseline <path>]
+
+Diagnostics go to stderr, add -v to show debug messages or -q to only show errors.";
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (options, args) = Options::parse(&args)?;
+    log::set_level(options.level);
+
+    match args.as_slice() {
+        ["run", selection @ ..] => run(&registry::select(selection)?, &options),
+        ["verify", selection @ ..] => verify(&registry::select(selection)?, &options),
+        ["bench", selection @ ..] => bench(&registry::select(selection)?, &options),
+        ["new", year, day] => new(year.parse()?, day.parse()?),
+        ["fetch", selection @ ..] => fetch(selection),
+        ["report"] => report(&options),
+        _ => bail!(USAGE),
+    }
+}
+
+#[derive(Debug)]
+struct Options {
+    input: Source,
+    output: Format,
+    iterations: usize,
+    baseline: PathBuf,
+    /// Relative slowdown of the median that is reported as a regression.
+    threshold: f64,
+    /// Update the baseline even if there are regressions, to accept them.
+    save_baseline: bool,
+    parallel: bool,
+    /// Time limit of a single puzzle when running in parallel.
+    timeout: Duration,
+    /// Time limit of parsing and of each part.
+    budget: Option<Duration>,
+    /// Count the allocations of every stage when benchmarking.
+    memory: bool,
+    level: Level,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            input: Source::default(),
+            output: Format::default(),
+            iterations: 10,
+            baseline: Path::new(env!("CARGO_MANIFEST_DIR")).join("bench.json"),
+            threshold: 0.1,
+            save_baseline: false,
+            parallel: false,
+            timeout: Duration::from_secs(60),
+            budget: None,
+            memory: false,
+            level: Level::default(),
+        }
+    }
+}
+
+impl Options {
+    /// Split the arguments into the flags and the remaining positional arguments.
+    fn parse(args: &[String]) -> Result<(Self, Vec<&str>)> {
+        let mut options = Options::default();
+        let mut positional = Vec::new();
+
+        let mut args = args.iter().map(String::as_str);
+        while let Some(arg) = args.next() {
+            match arg {
+                "--input" => options.input = Source::from_arg(value(&mut args, arg)?),
+                "--output" => options.output = value(&mut args, arg)?.parse()?,
+                "--iterations" => options.iterations = value(&mut args, arg)?.parse()?,
+                "--baseline" => options.baseline = PathBuf::from(value(&mut args, arg)?),
+                "--threshold" => options.threshold = value(&mut args, arg)?.parse::<f64>()? / 100.0,
+                "--save-baseline" => options.save_baseline = true,
+                "--parallel" => options.parallel = true,
+                "--memory" => options.memory = true,
+                "-v" | "--verbose" => options.level = Level::Debug,
+                "-q" | "--quiet" => options.level = Level::Error,
+                "--timeout" => options.timeout = seconds(value(&mut args, arg)?)?,
+                "--budget" => options.budget = Some(seconds(value(&mut args, arg)?)?),
+                _ => positional.push(arg),
+            }
+        }
+
+        Ok((options, positional))
+    }
+}
+
+fn seconds(value: &str) -> Result<Duration> {
+    Ok(Duration::try_from_secs_f64(value.parse()?)?)
+}
+
+fn value<'a>(args: &mut impl Iterator<Item = &'a str>, flag: &str) -> Result<&'a str> {
+    args.next().ok_or(anyhow!("Missing value for {}", flag))
+}
+
+fn run(puzzles: &[&'static Puzzle], options: &Options) -> Result<()> {
+    if puzzles.len() > 1 && options.input != Source::Default {
+        bail!("--input can only be used with a single puzzle");
+    }
+
+    if options.parallel {
+        return run_parallel(puzzles, options);
+    }
+
+    let mut failures = 0;
+    let mut all_results = Vec::new();
+
+    for puzzle in puzzles {
+        match input::load(&options.input, puzzle)
+            .and_then(|input| puzzle.solve(&input, options.budget))
+        {
+            Ok(results) => {
+                if options.output == Format::Text {
+                    print!("{}", output::text(puzzle.year, puzzle.day, &results));
+                }
+                all_results.extend(results);
+            }
+            // Parse errors already name the puzzle.
+            Err(e) if e.is::<ParseError>() => {
+                eprintln!("{}", e);
+                failures += 1;
+            }
+            Err(e) => {
+                eprintln!("{} day {}: {}", puzzle.year, puzzle.day, e);
+                failures += 1;
+            }
+        }
+    }
+
+    if options.output == Format::Json {
+        println!("{}", output::json(&all_results));
+    }
+
+    if failures > 0 {
+        bail!("{} of {} puzzles failed", failures, puzzles.len());
+    }
+
+    Ok(())
+}
+
+fn run_parallel(puzzles: &[&'static Puzzle], options: &Options) -> Result<()> {
+    let source = options.input.clone();
+    let budget = options.budget;
+    let outcomes = runner::run_parallel(puzzles, options.timeout, move |puzzle| {
+        puzzle.solve(&input::load(&source, puzzle)?, budget)
+    });
+
+    match options.output {
+        Format::Text => print!("{}", runner::summary(&outcomes)),
+        Format::Json => {
+            let results: Vec<_> = outcomes
+                .iter()
+                .filter_map(|outcome| match &outcome.status {
+                    Status::Solved(results) => Some(results.clone()),
+                    _ => None,
+                })
+                .flatten()
+                .collect();
+            println!("{}", output::json(&results));
+        }
+    }
+
+    let failures = outcomes.iter().filter(|o| !o.is_solved()).count();
+    if failures > 0 {
+        bail!("{} of {} puzzles failed", failures, puzzles.len());
+    }
+
+    Ok(())
+}
+
+fn verify(puzzles: &[&Puzzle], options: &Options) -> Result<()> {
+    if options.input != Source::Default {
+        bail!("--input can not be used with verify");
+    }
+
+    let mut passed = 0;
+    let mut missing = 0;
+    let mut failures = Vec::new();
+
+    println!("{:<12} {:<8} Part 2", "Puzzle", "Part 1");
+
+    for puzzle in puzzles {
+        let name = format!("{} day {}", puzzle.year, puzzle.day);
+
+        match answers::verify(puzzle) {
+            Ok(verdicts) => {
+                println!("{:<12} {:<8} {}", name, verdicts[0], verdicts[1]);
+
+                for (part, verdict) in verdicts.iter().enumerate() {
+                    match verdict {
+                        Verdict::Pass => passed += 1,
+                        Verdict::Missing => missing += 1,
+                        Verdict::Fail { expected, actual } => failures.push(format!(
+                            "{} part {}: expected {}, got {}",
+                            name,
+                            part + 1,
+                            expected,
+                            actual.as_deref().unwrap_or("nothing")
+                        )),
+                        Verdict::Unsolved => {}
+                    }
+                }
+            }
+            Err(e) => {
+                println!("{:<12} {:<8} error", name, "error");
+                failures.push(format!("{}: {}", name, e));
+            }
+        }
+    }
+
+    println!();
+    for failure in &failures {
+        println!("{}", failure);
+    }
+    println!(
+        "{} passed, {} failed, {} missing",
+        passed,
+        failures.len(),
+        missing
+    );
+
+    if !failures.is_empty() {
+        bail!("Verification failed");
+    }
+
+    Ok(())
+}
+
+fn bench(puzzles: &[&Puzzle], options: &Options) -> Result<()> {
+    if puzzles.len() > 1 && options.input != Source::Default {
+        bail!("--input can only be used with a single puzzle");
+    }
+
+    if options.memory {
+        memory::enable();
+    }
+
+    let previous = Baseline::load(&options.baseline)?;
+    let mut baseline = previous.clone();
+    let mut regressions = 0;
+    let mut failures = 0;
+
+    let memory_columns = |allocations: &str, bytes: &str, peak: &str| {
+        if options.memory {
+            format!(" {:>8} {:>10} {:>10}", allocations, bytes, peak)
+        } else {
+            String::new()
+        }
+    };
+    println!(
+        "{:<12} {:<9} {:>10} {:>10} {:>10}{} {:>8}",
+        "Puzzle",
+        "Stage",
+        "Min",
+        "Median",
+        "Max",
+        memory_columns("Allocs", "Bytes", "Peak"),
+        "Change"
+    );
+
+    for puzzle in puzzles {
+        let name = format!("{} day {}", puzzle.year, puzzle.day);
+
+        let stages = match measure_stages(puzzle, options) {
+            Ok(stages) => stages,
+            Err(e) => {
+                println!("{:<12} {:<9} error: {}", name, "-", e);
+                failures += 1;
+                continue;
+            }
+        };
+
+        for (stage, stats, usage) in stages {
+            let change = previous
+                .get(&puzzle.name(), stage)
+                .map(|previous| bench::change(previous, &stats));
+            let flag = match change {
+                Some(change) if change > options.threshold => {
+                    regressions += 1;
+                    "  REGRESSION"
+                }
+                _ => "",
+            };
+
+            println!(
+                "{:<12} {:<9} {:>10} {:>10} {:>10}{} {:>8}{}",
+                name,
+                stage,
+                format!("{:.1?}", stats.min),
+                format!("{:.1?}", stats.median),
+                format!("{:.1?}", stats.max),
+                memory_columns(
+                    &usage.allocations.to_string(),
+                    &memory::format_bytes(usage.bytes),
+                    &memory::format_bytes(usage.peak)
+                ),
+                change.map_or("-".to_string(), |c| format!("{:+.1}%", 100.0 * c)),
+                flag
+            );
+
+            baseline.insert(&puzzle.name(), stage, stats);
+        }
+    }
+
+    // Saving regressed timings would hide the regression from the next run.
+    if regressions == 0 || options.save_baseline {
+        baseline.save(&options.baseline)?;
+    } else {
+        log::log(
+            Level::Info,
+            format_args!("Baseline not updated, use --save-baseline to accept the regressions"),
+        );
+    }
+
+    if regressions > 0 || failures > 0 {
+        bail!("{} regressions, {} failures", regressions, failures);
+    }
+
+    Ok(())
+}
+
+/// Measure parsing, each solved part and the alternatives of the parts separately. Unsolved
+/// parts are skipped. Allocations are counted during a separate run of each stage, which is
+/// empty unless counting is enabled.
+fn measure_stages(puzzle: &Puzzle, options: &Options) -> Result<Vec<(&'static str, Stats, Usage)>> {
+    let input = input::load(&options.input, puzzle)?;
+
+    let (parsed, usage) = memory::measure(|| puzzle.parse(&input));
+    let parsed = parsed?;
+    let stats = bench::measure(options.iterations, || puzzle.parse(&input))?;
+    let mut stages = vec![("parse", stats, usage)];
+
+    let (answer, usage) = memory::measure(|| puzzle.part1(&parsed));
+    if answer?.is_some() {
+        let stats = bench::measure(options.iterations, || puzzle.part1(&parsed))?;
+        stages.push(("part1", stats, usage));
+    }
+    let (answer, usage) = memory::measure(|| puzzle.part2(&parsed));
+    if answer?.is_some() {
+        let stats = bench::measure(options.iterations, || puzzle.part2(&parsed))?;
+        stages.push(("part2", stats, usage));
+    }
+    for (stage, part) in puzzle.alternatives() {
+        let (_, usage) = memory::measure(|| part(&parsed));
+        let stats = bench::measure(options.iterations, || part(&parsed))?;
+        stages.push((stage, stats, usage));
+    }
+
+    Ok(stages)
+}
+
+fn new(year: u16, day: u8) -> Result<()> {
+    let dir = scaffold::create(Path::new(env!("CARGO_MANIFEST_DIR")), year, day)?;
+    println!("Created {}", dir.display());
+    Ok(())
+}
+
+/// Fetch the inputs of the selected puzzles. A single day doesn't need a solution yet, so that
+/// its input can be fetched before `aoc new`.
+fn fetch(selection: &[&str]) -> Result<()> {
+    let days = match selection {
+        [year, day] => vec![(year.parse()?, day.parse()?)],
+        _ => registry::select(selection)?
+            .iter()
+            .map(|puzzle| (puzzle.year, puzzle.day))
+            .collect(),
+    };
+    let mut fetcher = Fetcher::from_env()?;
+
+    for (year, day) in days {
+        match fetcher.fetch(year, day)? {
+            Fetched::Downloaded(path) => println!("Downloaded {}", path.display()),
+            Fetched::Cached(path) => println!("Cached {}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the Markdown progress report, e.g. to paste into the README.
+fn report(options: &Options) -> Result<()> {
+    let baseline = Baseline::load(&options.baseline)?;
+    let entries = report::collect(registry::PUZZLES, &baseline)?;
+    print!("{}", report::markdown(&entries));
+    Ok(())
+}
@@ -0,0 +1,235 @@
+use anyhow::{anyhow, Result};
+
+/// Greatest common divisor, always non-negative.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+/// Extended Euclid: returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Inverse of `a` modulo `m` in `0..m`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a as i128, m as i128);
+    if g != 1 || m <= 0 {
+        return None;
+    }
+    Some(x.rem_euclid(m as i128) as i64)
+}
+
+/// `a * b % m` without overflowing.
+pub fn mulmod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// `a * b` modulo `m` in `0..m`, for moduli up to `2^126` where widening is not possible.
+pub fn mulmod_i128(a: i128, b: i128, m: i128) -> i128 {
+    let (mut a, mut b) = (a.rem_euclid(m), b.rem_euclid(m));
+    let mut result = 0;
+
+    // Double and add, every intermediate value stays below `2 * m`.
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % m;
+        }
+        a = (a + a) % m;
+        b >>= 1;
+    }
+
+    result
+}
+
+/// `base^exp % m` by repeated squaring.
+pub fn modpow(base: u64, exp: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    let mut base = base % m;
+    let mut exp = exp;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mulmod(result, base, m);
+        }
+        base = mulmod(base, base, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Chinese remainder theorem: the smallest non-negative `x` with `x % m == r` for every
+/// `(r, m)` pair, together with the combined modulus. The moduli don't have to be coprime,
+/// `None` means there is no solution. Fails if the combined modulus doesn't fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Result<Option<(i64, i64)>> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+
+    for &(r, m) in congruences {
+        let (r, m) = (r as i128, m as i128);
+        if m <= 0 {
+            return Ok(None);
+        }
+
+        // Solve x + modulus * k == r (mod m) for k.
+        let (g, inverse, _) = extended_gcd(modulus, m);
+        if (r - x) % g != 0 {
+            return Ok(None);
+        }
+
+        let step = m / g;
+        let k = mulmod_i128((r - x) / g, inverse, step);
+        let overflow = || anyhow!("Combined modulus of {:?} overflows", congruences);
+        x = modulus
+            .checked_mul(k)
+            .and_then(|offset| x.checked_add(offset))
+            .ok_or_else(overflow)?;
+        modulus = modulus
+            .checked_mul(step)
+            .filter(|&modulus| modulus <= i64::MAX as i128)
+            .ok_or_else(overflow)?;
+        x = x.rem_euclid(modulus);
+    }
+
+    Ok(Some((x as i64, modulus as i64)))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(gcd(12, -18), 6);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(2, 4), None);
+        assert_eq!(modpow(2, 10, 1000), 24);
+        assert_eq!(modpow(5, 0, 1), 0);
+        assert_eq!(mulmod(u64::MAX, u64::MAX, 1_000_000_007), 114944269);
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]).unwrap(), Some((23, 105)));
+        assert_eq!(crt(&[(1, 4), (3, 6)]).unwrap(), Some((9, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]).unwrap(), None);
+        assert_eq!(crt(&[]).unwrap(), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_crt_large_moduli() {
+        // Two moduli close to 2^31 still fit, a third one close to 2^62 doesn't.
+        let (a, b) = ((1 << 31) - 1, (1 << 31) + 1);
+        assert_eq!(crt(&[(1, a), (1, b)]).unwrap(), Some((1, a * b)));
+        assert_eq!(crt(&[(a - 2, a), (0, b)]).unwrap(), Some((a * b - b, a * b)));
+
+        let primes = [(1 << 62) - 57, (1 << 62) - 87, (1 << 62) - 117];
+        assert!(crt(&primes.map(|p| (1, p))).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn gcd_divides_both(a in -1_000_000i64..1_000_000, b in -1_000_000i64..1_000_000) {
+            let g = gcd(a, b);
+            prop_assert!(g >= 0);
+            if g != 0 {
+                prop_assert_eq!(a % g, 0);
+                prop_assert_eq!(b % g, 0);
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+        }
+
+        #[test]
+        fn lcm_times_gcd(a in 1i64..1_000_000, b in 1i64..1_000_000) {
+            prop_assert_eq!(lcm(a, b) * gcd(a, b), a * b);
+        }
+
+        #[test]
+        fn extended_gcd_bezout(a in -(1i128 << 60)..(1i128 << 60), b in -(1i128 << 60)..(1i128 << 60)) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(a * x + b * y, g);
+            prop_assert_eq!(g as i64, gcd(a as i64, b as i64));
+        }
+
+        #[test]
+        fn mod_inverse_is_inverse(a in 1i64..i64::MAX, m in 2i64..i64::MAX) {
+            match mod_inverse(a, m) {
+                Some(inverse) => {
+                    prop_assert!((0..m).contains(&inverse));
+                    prop_assert_eq!(mulmod(a as u64, inverse as u64, m as u64), 1 % m as u64);
+                }
+                None => prop_assert_ne!(gcd(a, m), 1),
+            }
+        }
+
+        #[test]
+        fn mulmod_matches_widening(a: u64, b: u64, m in 1u64..) {
+            prop_assert_eq!(mulmod(a, b, m) as i128, mulmod_i128(a as i128, b as i128, m as i128));
+        }
+
+        #[test]
+        fn mulmod_i128_large_moduli(a: i128, b in 0i128..1000, m in 1i128..(1 << 126)) {
+            let expected = (0..b).fold(0, |acc, _| (acc + a.rem_euclid(m)) % m);
+            prop_assert_eq!(mulmod_i128(a, b, m), expected);
+        }
+
+        #[test]
+        fn modpow_adds_exponents(base: u64, e1 in 0u64..1 << 32, e2 in 0u64..1 << 32, m in 1u64..) {
+            prop_assert_eq!(
+                modpow(base, e1 + e2, m),
+                mulmod(modpow(base, e1, m), modpow(base, e2, m), m)
+            );
+        }
+
+        #[test]
+        fn crt_satisfies_congruences(
+            congruences in prop::collection::vec((0i64..1000, 1i64..1000), 0..5)
+        ) {
+            match crt(&congruences).unwrap() {
+                Some((x, modulus)) => {
+                    prop_assert!((0..modulus).contains(&x));
+                    for (r, m) in congruences {
+                        prop_assert_eq!(x % m, r % m);
+                    }
+                }
+                None => {
+                    // Some pair of congruences must contradict each other.
+                    let contradiction = congruences.iter().enumerate().any(|(i, &(r1, m1))| {
+                        congruences[i + 1..]
+                            .iter()
+                            .any(|&(r2, m2)| (r1 - r2) % gcd(m1, m2) != 0)
+                    });
+                    prop_assert!(contradiction);
+                }
+            }
+        }
+    }
+}
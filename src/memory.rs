@@ -0,0 +1,150 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+
+/// Global allocator that counts allocations once [`enable`]d, on top of the system allocator.
+/// The binary installs it, until then [`measure`] reports nothing.
+pub struct Counting;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+// Memory allocated before counting was enabled is never added, but may be freed, so the live
+// bytes are only meaningful relative to each other.
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+impl Counting {
+    fn allocated(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+            let live = LIVE.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+            PEAK.fetch_max(live, Ordering::Relaxed);
+        }
+    }
+
+    fn freed(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            LIVE.fetch_sub(size as i64, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::freed(layout.size());
+            Self::allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Start counting allocations, which slows down every allocation a little.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Allocations of a single run. The counters are global, allocations of other threads that
+/// run at the same time are included.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    /// Total size of all allocations, including those that were freed again.
+    pub bytes: u64,
+    /// Most bytes that were live at the same time, on top of those live before the run.
+    pub peak: u64,
+}
+
+/// Run `f` and count its allocations, see [`Usage`].
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: (PEAK.load(Ordering::Relaxed) - live).max(0) as u64,
+    };
+    (result, usage)
+}
+
+/// Size in binary units, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hint::black_box;
+
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        enable();
+
+        let (_, usage) = measure(|| {
+            let mut buffers: Vec<Vec<u8>> = Vec::new();
+            for _ in 0..4 {
+                buffers.push(black_box(vec![1; 1 << 20]));
+            }
+            drop(buffers);
+            black_box(vec![1u8; 1 << 20]);
+        });
+
+        // Other tests allocate at the same time.
+        assert!(usage.allocations >= 5);
+        assert!(usage.bytes >= 5 << 20);
+        assert!(usage.peak >= 4 << 20);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::bail;
+use serde_json::{json, Value};
+
+/// Answer to a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// Time it took to compute the answer.
+    pub elapsed: Duration,
+}
+
+impl PartResult {
+    pub fn to_json(&self) -> Value {
+        json!({
+            "year": self.year,
+            "day": self.day,
+            "part": self.part,
+            "answer": self.answer,
+            "elapsed_ns": self.elapsed.as_nanos() as u64,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("Unknown output format {}, expected text or json", s),
+        }
+    }
+}
+
+/// Render the results of a single puzzle in the same format as the known answers, with the time
+/// of the slowest part.
+pub fn text(year: u16, day: u8, results: &[PartResult]) -> String {
+    let slowest: Duration = results.iter().map(|r| r.elapsed).max().unwrap_or_default();
+
+    let mut text = format!("{} day {} (slowest part {:.1?})\n", year, day, slowest);
+    for result in results {
+        text += &format!("  Part {}: {}\n", result.part, result.answer);
+    }
+    text
+}
+
+pub fn json(results: &[PartResult]) -> String {
+    let results: Vec<Value> = results.iter().map(PartResult::to_json).collect();
+    serde_json::to_string_pretty(&results).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(part: u8, answer: &str) -> PartResult {
+        PartResult {
+            year: 2015,
+            day: 7,
+            part,
+            answer: answer.to_string(),
+            elapsed: Duration::from_millis(2),
+        }
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("text".parse::<Format>().unwrap(), Format::Text);
+        assert_eq!("json".parse::<Format>().unwrap(), Format::Json);
+        assert!("yaml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_text() {
+        let results = [result(1, "3176"), result(2, "14710")];
+        assert_eq!(
+            text(2015, 7, &results),
+            "2015 day 7 (slowest part 2.0ms)\n  Part 1: 3176\n  Part 2: 14710\n"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let json: Value = serde_json::from_str(&json(&[result(2, "abc")])).unwrap();
+        assert_eq!(
+            json,
+            json!([{
+                "year": 2015,
+                "day": 7,
+                "part": 2,
+                "answer": "abc",
+                "elapsed_ns": 2_000_000,
+            }])
+        );
+    }
+}
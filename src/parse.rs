@@ -0,0 +1,253 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use regex::Captures;
+
+/// Malformed puzzle input, pointing at the offending line and column where they are known.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseError {
+    /// Puzzle name such as `y2015d07`, filled in by the registry.
+    pub puzzle: Option<String>,
+    /// Line number, starting at 1.
+    pub line: Option<usize>,
+    /// Column in chars, starting at 1.
+    pub column: Option<usize>,
+    /// The offending line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error about the input as a whole, e.g. a missing section.
+    pub fn new(message: impl ToString) -> Self {
+        ParseError {
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Error on the given line, numbered from 1.
+    pub fn at_line(line: usize, text: &str, message: impl ToString) -> Self {
+        ParseError {
+            line: Some(line),
+            text: text.to_string(),
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_column(self, column: usize) -> Self {
+        ParseError {
+            column: Some(column),
+            ..self
+        }
+    }
+
+    /// Convert any error from parsing the input. Parse errors are kept as they are, other errors
+    /// only keep their message.
+    pub fn from_error(error: anyhow::Error) -> Self {
+        error
+            .downcast()
+            .unwrap_or_else(|error: anyhow::Error| ParseError::new(error))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(puzzle) = &self.puzzle {
+            write!(f, "{}: ", puzzle)?;
+        }
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            _ => {}
+        }
+        write!(f, "{}", self.message)?;
+
+        if self.line.is_some() {
+            write!(f, "\n  | {}", self.text)?;
+            if let Some(column) = self.column {
+                write!(f, "\n  | {:>width$}", "^", width = column)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse every line with `f`, turning failures into errors that point at the line.
+pub fn lines<'a, T>(input: &'a str, mut f: impl FnMut(&'a str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| in_line(e, i + 1, line).into()))
+        .collect()
+}
+
+/// Attach the line to an error from parsing it, keeping the column of a `ParseError`.
+pub fn in_line(error: anyhow::Error, line: usize, text: &str) -> ParseError {
+    let error = ParseError::from_error(error);
+    ParseError {
+        line: Some(line),
+        text: text.to_string(),
+        ..error
+    }
+}
+
+/// Shift the line number of an error from parsing a section that starts after `offset` lines.
+pub fn offset_lines(error: anyhow::Error, offset: usize) -> ParseError {
+    let error = ParseError::from_error(error);
+    ParseError {
+        line: error.line.map(|line| line + offset),
+        ..error
+    }
+}
+
+/// Declare a struct parsed from a line matching a regex. Every field is parsed with `FromStr`
+/// from the capture group of the same name, other groups are ignored.
+///
+/// ```ignore
+/// regex_struct! {
+///     #[regex = r"^(?<name>.+) can fly (?<speed>\d+) km/s$"]
+///     #[derive(Debug)]
+///     pub struct Reindeer {
+///         name: String,
+///         speed: i32,
+///     }
+/// }
+/// ```
+macro_rules! regex_struct {
+    (
+        #[regex = $pattern:literal]
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field_vis:vis $field:ident: $ty:ty),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $($(#[$field_meta])* $field_vis $field: $ty),*
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = ::anyhow::Error;
+
+            fn from_str(s: &str) -> ::anyhow::Result<Self> {
+                ::lazy_static::lazy_static! {
+                    static ref RE: ::regex::Regex = ::regex::Regex::new($pattern).unwrap();
+                }
+
+                let captures = RE
+                    .captures(s)
+                    .ok_or(::anyhow::anyhow!("Unable to match {}", s))?;
+
+                Ok($name {
+                    $($field: $crate::parse::capture(s, &captures, stringify!($field))?),*
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use regex_struct;
+
+/// Parse the named group of a match in `line`, pointing errors at the group.
+pub fn capture<T>(line: &str, captures: &Captures, name: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let group = captures
+        .name(name)
+        .ok_or(anyhow!("Missing {} in {}", name, line))?;
+
+    group.as_str().parse().map_err(|e| {
+        let message = format!("Invalid {} {:?}: {}", name, group.as_str(), e);
+        let column = line[..group.start()].chars().count() + 1;
+        ParseError::new(message).with_column(column).into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = ParseError {
+            puzzle: Some("y2019d18".into()),
+            ..ParseError::at_line(3, "#.?#", "Invalid tile ?").with_column(3)
+        };
+        assert_eq!(
+            error.to_string(),
+            "y2019d18: line 3, column 3: Invalid tile ?\n  | #.?#\n  |   ^"
+        );
+
+        assert_eq!(
+            ParseError::at_line(2, "cut x", "invalid digit").to_string(),
+            "line 2: invalid digit\n  | cut x"
+        );
+        assert_eq!(
+            ParseError::new("Missing start").to_string(),
+            "Missing start"
+        );
+    }
+
+    #[test]
+    fn test_lines() {
+        let numbers: Vec<i32> = lines("1\n2\n3\n", |l| Ok(l.parse()?)).unwrap();
+        assert_eq!(numbers, [1, 2, 3]);
+
+        let error = lines("1\nx\n3\n", |l| Ok(l.parse::<i32>()?)).unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (Some(2), None));
+        assert_eq!(error.text, "x");
+    }
+
+    #[test]
+    fn test_offset_lines() {
+        let error = ParseError::at_line(2, "x", "bad").with_column(1);
+        let error = offset_lines(error.into(), 10);
+        assert_eq!((error.line, error.column), (Some(12), Some(1)));
+
+        let error = offset_lines(anyhow!("bad"), 10);
+        assert_eq!(error.line, None);
+    }
+
+    regex_struct! {
+        #[regex = r"^(?<name>\w+) is (?<age>\d+)( years old)?$"]
+        #[derive(Debug, PartialEq)]
+        struct Person {
+            name: String,
+            age: u8,
+        }
+    }
+
+    #[test]
+    fn test_regex_struct() {
+        let person: Person = "Ann is 30 years old".parse().unwrap();
+        assert_eq!(
+            person,
+            Person {
+                name: "Ann".into(),
+                age: 30
+            }
+        );
+
+        let error = "Bob is thirty".parse::<Person>().unwrap_err();
+        assert_eq!(error.to_string(), "Unable to match Bob is thirty");
+
+        let error = lines("Ann is 30\nBob is 300\n", str::parse::<Person>).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 8: Invalid age \"300\": number too large to fit in target type\n  \
+             | Bob is 300\n  |        ^"
+        );
+    }
+}
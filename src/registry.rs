@@ -0,0 +1,315 @@
+use std::any::Any;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Result};
+
+use crate::cancel::{self, Cancelled};
+use crate::output::PartResult;
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+use crate::solutions::*;
+
+/// Answers produced by a single run of a puzzle. Parts that are not solved yet are `None`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[cfg(test)]
+impl Answers {
+    pub fn new(part1: impl ToString, part2: impl ToString) -> Self {
+        Self {
+            part1: Some(part1.to_string()),
+            part2: Some(part2.to_string()),
+        }
+    }
+
+    pub fn part2(answer: impl ToString) -> Self {
+        Self {
+            part1: None,
+            part2: Some(answer.to_string()),
+        }
+    }
+}
+
+/// Parsed input of a puzzle, only usable with the puzzle that parsed it.
+pub type Input = Box<dyn Any>;
+
+/// A solution registered for a specific day, with its input type erased.
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    parse: fn(&str) -> Result<Input>,
+    part1: fn(&Input) -> Result<Answer>,
+    part2: fn(&Input) -> Result<Answer>,
+    alternatives: fn() -> Vec<Alternative>,
+}
+
+/// Alternative implementation of a part, see [`Solution::alternatives`].
+pub type Alternative = (&'static str, Box<dyn Fn(&Input) -> Result<Answer>>);
+
+impl Puzzle {
+    pub const fn new<S: Solution>(year: u16, day: u8) -> Self
+    where
+        S::Input: 'static,
+    {
+        Puzzle {
+            year,
+            day,
+            parse: |input| Ok(Box::new(S::parse(input)?)),
+            part1: |input| S::part1(downcast::<S>(input)),
+            part2: |input| S::part2(downcast::<S>(input)),
+            alternatives: || {
+                S::alternatives()
+                    .into_iter()
+                    .map(|(stage, part)| {
+                        let part = move |input: &Input| part(downcast::<S>(input));
+                        (
+                            stage,
+                            Box::new(part) as Box<dyn Fn(&Input) -> Result<Answer>>,
+                        )
+                    })
+                    .collect()
+            },
+        }
+    }
+
+    /// Parse the input, failing with a `ParseError` that names the puzzle.
+    pub fn parse(&self, input: &str) -> Result<Input> {
+        (self.parse)(input).map_err(|e| {
+            if e.is::<Cancelled>() {
+                return e;
+            }
+            ParseError {
+                puzzle: Some(self.name()),
+                ..ParseError::from_error(e)
+            }
+            .into()
+        })
+    }
+
+    pub fn part1(&self, input: &Input) -> Result<Answer> {
+        (self.part1)(input)
+    }
+
+    pub fn part2(&self, input: &Input) -> Result<Answer> {
+        (self.part2)(input)
+    }
+
+    pub fn alternatives(&self) -> Vec<Alternative> {
+        (self.alternatives)()
+    }
+
+    /// Parse the input and solve both parts.
+    pub fn run(&self, input: &str) -> Result<Answers> {
+        let input = self.parse(input)?;
+
+        Ok(Answers {
+            part1: self.part1(&input)?,
+            part2: self.part2(&input)?,
+        })
+    }
+
+    /// Name of the puzzle module, e.g. `y2015d07`.
+    pub fn name(&self) -> String {
+        format!("y{}d{:02}", self.year, self.day)
+    }
+
+    /// Run the solution and collect the results of the solved parts, timing each part on its own.
+    /// Parsing and each part fail with [`Cancelled`] when they take longer than `budget`.
+    pub fn solve(&self, input: &str, budget: Option<Duration>) -> Result<Vec<PartResult>> {
+        let input = within(budget, "Parsing", || self.parse(input))?;
+
+        let mut results = Vec::new();
+        for (part, solve) in [(1, self.part1), (2, self.part2)] {
+            let instant = Instant::now();
+            let answer = within(budget, &format!("Part {}", part), || solve(&input))?;
+            let elapsed = instant.elapsed();
+
+            if let Some(answer) = answer {
+                results.push(PartResult {
+                    year: self.year,
+                    day: self.day,
+                    part,
+                    answer,
+                    elapsed,
+                });
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+impl fmt::Debug for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Puzzle")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Run a stage under the current cancellation token, limited to `budget`.
+fn within<T>(budget: Option<Duration>, stage: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let token = cancel::current().unwrap_or_default();
+    let token = match budget {
+        Some(budget) => token.with_budget(budget),
+        None => token,
+    };
+
+    cancel::scope(token, f).map_err(|e| match budget {
+        Some(budget) if e.is::<Cancelled>() => {
+            e.context(format!("{} took longer than {:.1?}", stage, budget))
+        }
+        _ => e,
+    })
+}
+
+fn downcast<S: Solution>(input: &Input) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("Input parsed by a different puzzle")
+}
+
+macro_rules! puzzles {
+    ($($year:literal $day:literal => $module:ident,)*) => {
+        /// All the solved puzzles, ordered by year and day.
+        pub const PUZZLES: &[Puzzle] = &[
+            $(Puzzle::new::<$module::Day>($year, $day),)*
+        ];
+    };
+}
+
+puzzles! {
+    2015 1 => y2015d01,
+    2015 3 => y2015d03,
+    2015 4 => y2015d04,
+    2015 5 => y2015d05,
+    2015 6 => y2015d06,
+    2015 7 => y2015d07,
+    2015 8 => y2015d08,
+    2015 9 => y2015d09,
+    2015 10 => y2015d10,
+    2015 11 => y2015d11,
+    2015 12 => y2015d12,
+    2015 13 => y2015d13,
+    2015 14 => y2015d14,
+    2015 15 => y2015d15,
+    2015 16 => y2015d16,
+    2015 17 => y2015d17,
+    2015 18 => y2015d18,
+    2015 19 => y2015d19,
+    2015 20 => y2015d20,
+    2015 21 => y2015d21,
+    2015 22 => y2015d22,
+    2015 23 => y2015d23,
+    2015 24 => y2015d24,
+    2015 25 => y2015d25,
+    2016 1 => y2016d01,
+    2018 23 => y2018d23,
+    2019 18 => y2019d18,
+    2019 22 => y2019d22,
+    2022 19 => y2022d19,
+    2024 1 => y2024d01,
+    2024 3 => y2024d03,
+    2024 8 => y2024d08,
+    2024 20 => y2024d20,
+    2024 22 => y2024d22,
+    2024 25 => y2024d25,
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|p| p.year == year && p.day == day)
+}
+
+/// Select puzzles from command line style arguments: `all`, `<year>` or `<year> <day>`.
+/// Fails unless there is a puzzle for the day, whether it has a solution or not.
+pub fn check_day(year: u16, day: u8) -> Result<()> {
+    if year < 2015 || !(1..=25).contains(&day) {
+        bail!("There is no puzzle for {} day {}", year, day);
+    }
+    Ok(())
+}
+
+pub fn select(args: &[&str]) -> Result<Vec<&'static Puzzle>> {
+    let selected: Vec<_> = match args {
+        ["all"] => PUZZLES.iter().collect(),
+        [year] => {
+            let year: u16 = year.parse()?;
+            PUZZLES.iter().filter(|p| p.year == year).collect()
+        }
+        [year, day] => {
+            let (year, day) = (year.parse()?, day.parse()?);
+            match find(year, day) {
+                Some(puzzle) => vec![puzzle],
+                None => bail!("No solution for {} day {}", year, day),
+            }
+        }
+        _ => bail!("Expected `all`, `<year>` or `<year> <day>`"),
+    };
+
+    if selected.is_empty() {
+        bail!("No solutions for {}", args.join(" "));
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzles_are_ordered() {
+        assert!(PUZZLES
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(select(&["all"]).unwrap().len(), PUZZLES.len());
+        assert_eq!(select(&["2024"]).unwrap().len(), 6);
+        assert_eq!(select(&["2015", "7"]).unwrap()[0].name(), "y2015d07");
+        assert_eq!(select(&["2015", "07"]).unwrap()[0].name(), "y2015d07");
+        assert!(select(&["2015", "2"]).is_err());
+        assert!(select(&["2017"]).is_err());
+        assert!(select(&["seven"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let puzzle = find(2019, 22).unwrap();
+        let error = puzzle.parse("cut 3\ncut x\n").unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!(error.puzzle.as_deref(), Some("y2019d22"));
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.text, "cut x");
+
+        let error = find(2019, 18).unwrap().parse("#@#\n#?#\n").unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+
+        let error = find(2015, 4).unwrap().parse("").unwrap_err();
+        assert_eq!(error.to_string(), "y2015d04: Missing secret key");
+    }
+
+    #[test]
+    fn test_solve_budget() {
+        let puzzle = find(2015, 22).unwrap();
+        let input = "Hit Points: 51\nDamage: 9\n";
+
+        let error = puzzle.solve(input, Some(Duration::ZERO)).unwrap_err();
+        assert!(error.is::<Cancelled>());
+        assert_eq!(error.to_string(), "Part 2 took longer than 0.0ns");
+
+        let results = puzzle.solve(input, Some(Duration::from_secs(60))).unwrap();
+        assert_eq!(results[0].answer, "1216");
+    }
+}
@@ -0,0 +1,166 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::answers;
+use crate::bench::Baseline;
+use crate::input;
+use crate::registry::Puzzle;
+
+/// Progress of a single puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// Whether each part has a known answer.
+    pub parts: [bool; 2],
+    /// Sum of the median times of parsing and the parts in the benchmark baseline.
+    pub runtime: Option<Duration>,
+}
+
+impl Entry {
+    pub fn stars(&self) -> usize {
+        self.parts.iter().filter(|&&p| p).count()
+    }
+}
+
+/// Collect the progress of the puzzles from their known answers and the benchmark baseline.
+pub fn collect(puzzles: &[Puzzle], baseline: &Baseline) -> Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+
+    for puzzle in puzzles {
+        let answers = match input::locate(puzzle) {
+            Ok(path) => answers::load(&path)?.unwrap_or_default(),
+            Err(_) => Default::default(),
+        };
+        let runtime = baseline.0.get(&puzzle.name()).map(|stages| {
+            // Alternatives of the parts are benchmarked as well, but never run.
+            ["parse", "part1", "part2"]
+                .iter()
+                .filter_map(|&stage| stages.get(stage))
+                .map(|s| s.median)
+                .sum()
+        });
+
+        entries.push(Entry {
+            year: puzzle.year,
+            day: puzzle.day,
+            parts: [answers.part1.is_some(), answers.part2.is_some()],
+            runtime,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Render a calendar with the stars of every year, followed by a table per year.
+pub fn markdown(entries: &[Entry]) -> String {
+    let by_year = entries.iter().into_group_map_by(|e| e.year);
+    let years = by_year.keys().copied().sorted().collect_vec();
+
+    let mut md = String::from("# Progress\n\n| Year | Stars |");
+    for day in 1..=25 {
+        write!(md, " {} |", day).unwrap();
+    }
+    md += "\n| --- | ---: |";
+    md += &" :-: |".repeat(25);
+    md += "\n";
+
+    for year in &years {
+        let entries = &by_year[year];
+        let stars: usize = entries.iter().map(|e| e.stars()).sum();
+        write!(md, "| {} | {} |", year, stars).unwrap();
+
+        for day in 1..=25 {
+            let cell = match entries.iter().find(|e| e.day == day) {
+                Some(entry) if entry.stars() > 0 => "★".repeat(entry.stars()),
+                // Registered, but without known answers.
+                Some(_) => "·".to_string(),
+                None => String::new(),
+            };
+            write!(md, " {} |", cell).unwrap();
+        }
+        md += "\n";
+    }
+
+    for year in &years {
+        write!(md, "\n## {}\n\n", year).unwrap();
+        md += "| Day | Part 1 | Part 2 | Runtime |\n| ---: | :-: | :-: | ---: |\n";
+
+        for entry in by_year[year].iter().sorted_by_key(|e| e.day) {
+            let star = |solved| if solved { "★" } else { "" };
+            let runtime = entry
+                .runtime
+                .map_or("-".to_string(), |r| format!("{:.1?}", r));
+            writeln!(
+                md,
+                "| {} | {} | {} | {} |",
+                entry.day,
+                star(entry.parts[0]),
+                star(entry.parts[1]),
+                runtime
+            )
+            .unwrap();
+        }
+    }
+
+    md
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Stats;
+    use crate::registry;
+
+    fn entry(year: u16, day: u8, parts: [bool; 2], runtime: Option<u64>) -> Entry {
+        Entry {
+            year,
+            day,
+            parts,
+            runtime: runtime.map(Duration::from_millis),
+        }
+    }
+
+    #[test]
+    fn test_markdown() {
+        let md = markdown(&[
+            entry(2019, 22, [true, false], None),
+            entry(2015, 2, [false, false], None),
+            entry(2015, 1, [true, true], Some(12)),
+        ]);
+        let lines = md.lines().collect_vec();
+
+        assert_eq!(lines[0], "# Progress");
+        assert!(lines[2].starts_with("| Year | Stars | 1 | 2 | 3 |"));
+        assert!(lines[4].starts_with("| 2015 | 2 | ★★ | · |  |"));
+        assert!(lines[5].starts_with("| 2019 | 1 |"));
+        assert!(lines[5].ends_with(" ★ |  |  |  |"));
+
+        assert!(md.contains("## 2015\n\n| Day | Part 1 | Part 2 | Runtime |\n| ---: | :-: | :-: | ---: |\n| 1 | ★ | ★ | 12.0ms |\n| 2 |  |  | - |\n"));
+        assert!(md.ends_with("## 2019\n\n| Day | Part 1 | Part 2 | Runtime |\n| ---: | :-: | :-: | ---: |\n| 22 | ★ |  | - |\n"));
+    }
+
+    #[test]
+    fn test_collect() {
+        let mut baseline = Baseline::default();
+        let stats = |ms| Stats {
+            min: Duration::from_millis(ms),
+            median: Duration::from_millis(ms),
+            max: Duration::from_millis(ms),
+        };
+        baseline.insert("y2024d20", "parse", stats(1));
+        baseline.insert("y2024d20", "part1", stats(2));
+        baseline.insert("y2024d20", "part1-old", stats(20));
+
+        let puzzle = registry::find(2024, 20).unwrap();
+        let entries = collect(std::slice::from_ref(puzzle), &baseline).unwrap();
+        assert_eq!(
+            entries,
+            [entry(2024, 20, [true, false], Some(3))],
+            "Only part 1 of y2024d20 is solved"
+        );
+    }
+}
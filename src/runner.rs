@@ -0,0 +1,305 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::cancel::{self, Cancelled, Token};
+use crate::output::PartResult;
+use crate::registry::Puzzle;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Solved(Vec<PartResult>),
+    Failed(String),
+    Panicked(String),
+    TimedOut,
+}
+
+/// Result of running a single puzzle.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub puzzle: &'static Puzzle,
+    pub status: Status,
+    /// Wall-clock time including loading and parsing the input.
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    pub fn is_solved(&self) -> bool {
+        matches!(self.status, Status::Solved(_))
+    }
+}
+
+enum Message {
+    Started(usize, Instant),
+    Finished(usize, Status, Duration),
+}
+
+/// Run `job` for every puzzle concurrently, on at most as many threads as there are cores.
+/// Panics are caught per puzzle, and puzzles that take longer than `timeout` are reported as
+/// timed out. The job runs in a [`cancel::scope`] whose token is cancelled on timeout, jobs
+/// that don't check it are left behind on their thread and no longer count towards the limit.
+/// Jobs failing with [`Cancelled`] are reported as timed out as well. Outcomes are in the same
+/// order as the puzzles.
+pub fn run_parallel<F>(puzzles: &[&'static Puzzle], timeout: Duration, job: F) -> Vec<Outcome>
+where
+    F: Fn(&'static Puzzle) -> Result<Vec<PartResult>> + Send + Sync + 'static,
+{
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let job = Arc::new(job);
+    let (sender, receiver) = mpsc::channel();
+
+    let mut queue = puzzles.iter().copied().enumerate();
+    let mut outcomes: Vec<Option<Outcome>> = vec![None; puzzles.len()];
+    let mut running: HashMap<usize, (Token, Option<Instant>)> = HashMap::new();
+
+    loop {
+        while running.len() < threads {
+            let Some((i, puzzle)) = queue.next() else {
+                break;
+            };
+            let token = Token::new();
+            running.insert(i, (token.clone(), None));
+            spawn(i, puzzle, token, job.clone(), sender.clone());
+        }
+
+        if running.is_empty() {
+            break;
+        }
+
+        let deadline = running
+            .values()
+            .filter_map(|(_, start)| *start)
+            .min()
+            .map(|start| start + timeout);
+        let message = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        match message {
+            Ok(Message::Started(i, start)) => {
+                if let Some((_, started)) = running.get_mut(&i) {
+                    *started = Some(start);
+                }
+            }
+            // Late results of timed out jobs are ignored.
+            Ok(Message::Finished(i, status, elapsed)) if running.remove(&i).is_some() => {
+                outcomes[i] = Some(Outcome {
+                    puzzle: puzzles[i],
+                    status,
+                    elapsed,
+                });
+            }
+            Ok(Message::Finished(..)) => {}
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                let timed_out: Vec<usize> = running
+                    .iter()
+                    .filter(|(_, (_, start))| start.is_some_and(|start| now >= start + timeout))
+                    .map(|(&i, _)| i)
+                    .collect();
+
+                for i in timed_out {
+                    if let Some((token, _)) = running.remove(&i) {
+                        token.cancel();
+                    }
+                    outcomes[i] = Some(Outcome {
+                        puzzle: puzzles[i],
+                        status: Status::TimedOut,
+                        elapsed: timeout,
+                    });
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("The sender is kept alive"),
+        }
+    }
+
+    outcomes.into_iter().flatten().collect()
+}
+
+fn spawn<F>(
+    i: usize,
+    puzzle: &'static Puzzle,
+    token: Token,
+    job: Arc<F>,
+    sender: mpsc::Sender<Message>,
+) where
+    F: Fn(&'static Puzzle) -> Result<Vec<PartResult>> + Send + Sync + 'static,
+{
+    // Named threads make the panic messages point at the puzzle.
+    thread::Builder::new()
+        .name(puzzle.name())
+        .spawn(move || {
+            let start = Instant::now();
+            // Sending fails only once the run is over, when nobody waits for the job anymore.
+            let _ = sender.send(Message::Started(i, start));
+
+            let job = || cancel::scope(token, || job(puzzle));
+            let status = match panic::catch_unwind(AssertUnwindSafe(job)) {
+                Ok(Ok(results)) => Status::Solved(results),
+                Ok(Err(e)) if e.is::<Cancelled>() => Status::TimedOut,
+                Ok(Err(e)) => Status::Failed(e.to_string()),
+                Err(payload) => Status::Panicked(panic_message(payload.as_ref())),
+            };
+            let _ = sender.send(Message::Finished(i, status, start.elapsed()));
+        })
+        .expect("Unable to spawn a thread");
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown panic".to_string()
+    }
+}
+
+/// Table with a row per puzzle: the status, the time and the answers or the error.
+pub fn summary(outcomes: &[Outcome]) -> String {
+    let mut summary = format!(
+        "{:<12} {:<8} {:>10}  {:<16} {}\n",
+        "Puzzle", "Status", "Time", "Part 1", "Part 2"
+    );
+
+    for outcome in outcomes {
+        let name = format!("{} day {}", outcome.puzzle.year, outcome.puzzle.day);
+        let (status, details) = match &outcome.status {
+            Status::Solved(results) => {
+                let answer = |part| {
+                    results
+                        .iter()
+                        .find(|r| r.part == part)
+                        .map_or("-", |r| r.answer.as_str())
+                };
+                let details = format!("{:<16} {}", answer(1), answer(2));
+                ("ok", details)
+            }
+            Status::Failed(e) => ("error", e.clone()),
+            Status::Panicked(message) => ("panic", message.clone()),
+            Status::TimedOut => ("timeout", String::new()),
+        };
+
+        // Multi-line errors, e.g. parse errors, would break the table.
+        let details = details.lines().next().unwrap_or_default();
+        let time = format!("{:.1?}", outcome.elapsed);
+        writeln!(
+            summary,
+            "{:<12} {:<8} {:>10}  {}",
+            name, status, time, details
+        )
+        .unwrap();
+    }
+
+    let solved = outcomes.iter().filter(|o| o.is_solved()).count();
+    writeln!(summary, "\n{} of {} puzzles solved", solved, outcomes.len()).unwrap();
+
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::bail;
+
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_run_parallel() {
+        let puzzles = ["2015", "all"].map(|s| registry::select(&[s]).unwrap());
+        let (few, all) = (&puzzles[0][..4], &puzzles[1]);
+
+        let outcomes = run_parallel(few, Duration::from_millis(300), |puzzle| match puzzle.day {
+            1 => Ok(vec![]),
+            3 => bail!("Broken"),
+            4 => panic!("Out of bounds"),
+            _ => {
+                thread::sleep(Duration::from_secs(5));
+                Ok(vec![])
+            }
+        });
+
+        let statuses: Vec<_> = outcomes.iter().map(|o| o.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            [
+                Status::Solved(vec![]),
+                Status::Failed("Broken".into()),
+                Status::Panicked("Out of bounds".into()),
+                Status::TimedOut,
+            ]
+        );
+        assert_eq!(outcomes[3].puzzle.name(), "y2015d05");
+
+        // More puzzles than threads, in order.
+        let outcomes = run_parallel(all, Duration::from_secs(10), |_| Ok(vec![]));
+        assert!(outcomes.iter().all(Outcome::is_solved));
+        assert!(outcomes
+            .iter()
+            .zip(all.iter())
+            .all(|(o, p)| std::ptr::eq(o.puzzle, *p)));
+    }
+
+    #[test]
+    fn test_run_parallel_cancel() {
+        let puzzles = registry::select(&["2015", "22"]).unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        // Cooperative jobs stop once their token is cancelled on timeout.
+        let outcomes = run_parallel(&puzzles, Duration::from_millis(100), move |_| loop {
+            if let Err(e) = cancel::check() {
+                sender.send(()).unwrap();
+                return Err(e);
+            }
+            thread::sleep(Duration::from_millis(1));
+        });
+        assert_eq!(outcomes[0].status, Status::TimedOut);
+        assert!(receiver.recv_timeout(Duration::from_secs(5)).is_ok());
+
+        // Cancelled jobs count as timed out.
+        let outcomes = run_parallel(&puzzles, Duration::from_secs(10), |_| Err(Cancelled.into()));
+        assert_eq!(outcomes[0].status, Status::TimedOut);
+    }
+
+    #[test]
+    fn test_summary() {
+        let puzzle = registry::find(2015, 7).unwrap();
+        let outcome = |status| Outcome {
+            puzzle,
+            status,
+            elapsed: Duration::from_millis(2),
+        };
+        let result = PartResult {
+            year: 2015,
+            day: 7,
+            part: 2,
+            answer: "42".into(),
+            elapsed: Duration::from_millis(1),
+        };
+
+        let summary = summary(&[
+            outcome(Status::Solved(vec![result])),
+            outcome(Status::Failed("line 1: Bad\n  | x".into())),
+            outcome(Status::TimedOut),
+        ]);
+        assert_eq!(
+            summary,
+            "Puzzle       Status         Time  Part 1           Part 2\n\
+             2015 day 7   ok            2.0ms  -                42\n\
+             2015 day 7   error         2.0ms  line 1: Bad\n\
+             2015 day 7   timeout       2.0ms  \n\
+             \n\
+             1 of 3 puzzles solved\n"
+        );
+    }
+}
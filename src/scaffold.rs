@@ -0,0 +1,186 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::registry;
+
+/// Skeleton of a new solution module.
+const TEMPLATE: &str = r#"use anyhow::Result;
+
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, |line| Ok(line.to_string()))
+    }
+
+    fn part1(_lines: &Self::Input) -> Result<Answer> {
+        Ok(None)
+    }
+
+    fn part2(_lines: &Self::Input) -> Result<Answer> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Day::parse("a\nb\n").unwrap(), ["a", "b"]);
+    }
+}
+"#;
+
+lazy_static! {
+    static ref MOD_RE: Regex = Regex::new(r"^pub mod (y\d{4}d\d{2});$").unwrap();
+    static ref REGISTRY_RE: Regex = Regex::new(r"^\s*\d+ \d+ => (y\d{4}d\d{2}),$").unwrap();
+}
+
+/// Create the module of a new puzzle under `root`, with an empty input, an example fixture and
+/// a test stub, and register it. Refuses to touch a puzzle that already has a module, but keeps
+/// files that were put into the directory before, e.g. an input copied there by hand. Inputs
+/// from `aoc fetch` are in the inputs directory instead and are found there when running.
+pub fn create(root: &Path, year: u16, day: u8) -> Result<PathBuf> {
+    registry::check_day(year, day)?;
+
+    let name = format!("y{}d{:02}", year, day);
+    let dir = root.join("src/solutions").join(&name);
+    let module = dir.join("mod.rs");
+    if module.exists() {
+        bail!("{} already exists", module.display());
+    }
+
+    // Prepare both registrations first, so that nothing is written if either fails.
+    let mod_path = root.join("src/solutions/mod.rs");
+    let registry_path = root.join("src/registry.rs");
+    let modules = insert_entry(
+        &read(&mod_path)?,
+        &MOD_RE,
+        &name,
+        &format!("pub mod {};", name),
+    )?;
+    let registry = insert_entry(
+        &read(&registry_path)?,
+        &REGISTRY_RE,
+        &name,
+        &format!("    {} {} => {},", year, day, name),
+    )?;
+
+    fs::create_dir_all(dir.join("examples"))?;
+    fs::write(&module, TEMPLATE)?;
+    for file in [
+        "input.txt",
+        "examples/example.txt",
+        "examples/example.answers.txt",
+    ] {
+        let path = dir.join(file);
+        if !path.exists() {
+            fs::write(path, "")?;
+        }
+    }
+    fs::write(&mod_path, modules)?;
+    fs::write(&registry_path, registry)?;
+
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Unable to read {}", path.display()))
+}
+
+/// Insert `line` among the lines matching `entry`, keeping them sorted by the module name they
+/// capture.
+fn insert_entry(text: &str, entry: &Regex, name: &str, line: &str) -> Result<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let entries: Vec<(usize, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| Some((i, entry.captures(l)?.get(1)?.as_str())))
+        .collect();
+
+    if entries.iter().any(|&(_, n)| n == name) {
+        bail!("{} is already registered", name);
+    }
+
+    let (last, _) = entries
+        .last()
+        .ok_or(anyhow!("No entries like {} found", line.trim()))?;
+    let index = entries
+        .iter()
+        .find(|&&(_, n)| n > name)
+        .map_or(last + 1, |&(i, _)| i);
+    lines.insert(index, line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "puzzles! {\n    2015 1 => y2015d01,\n    2015 10 => y2015d10,\n}\n";
+
+    #[test]
+    fn test_insert_entry() {
+        let insert = |name, line| insert_entry(REGISTRY, &REGISTRY_RE, name, line);
+
+        assert_eq!(
+            insert("y2015d02", "    2015 2 => y2015d02,").unwrap(),
+            "puzzles! {\n    2015 1 => y2015d01,\n    2015 2 => y2015d02,\n    2015 10 => y2015d10,\n}\n"
+        );
+        assert!(insert("y2016d01", "    2016 1 => y2016d01,")
+            .unwrap()
+            .ends_with("y2015d10,\n    2016 1 => y2016d01,\n}\n"));
+        assert!(insert("y2015d10", "    2015 10 => y2015d10,").is_err());
+        assert!(insert_entry("", &MOD_RE, "y2015d01", "pub mod y2015d01;").is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/solutions")).unwrap();
+        fs::write(root.join("src/solutions/mod.rs"), "pub mod y2015d01;\n").unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+
+        let dir = create(&root, 2015, 7).unwrap();
+        assert!(dir.join("mod.rs").is_file());
+        assert!(dir.join("examples/example.answers.txt").is_file());
+        assert_eq!(fs::read_to_string(dir.join("input.txt")).unwrap(), "");
+
+        let modules = fs::read_to_string(root.join("src/solutions/mod.rs")).unwrap();
+        assert_eq!(modules, "pub mod y2015d01;\npub mod y2015d07;\n");
+        let registry = fs::read_to_string(root.join("src/registry.rs")).unwrap();
+        assert!(registry.contains("    2015 7 => y2015d07,\n    2015 10"));
+
+        // Existing days are never overwritten.
+        fs::write(dir.join("input.txt"), "42").unwrap();
+        assert!(create(&root, 2015, 7).is_err());
+        assert_eq!(fs::read_to_string(dir.join("input.txt")).unwrap(), "42");
+        assert!(create(&root, 2015, 26).is_err());
+
+        // An input that is in the directory already is kept.
+        let existing = root.join("src/solutions/y2015d08");
+        fs::create_dir_all(&existing).unwrap();
+        fs::write(existing.join("input.txt"), "\"\"").unwrap();
+        assert_eq!(create(&root, 2015, 8).unwrap(), existing);
+        assert!(existing.join("mod.rs").is_file());
+        assert_eq!(
+            fs::read_to_string(existing.join("input.txt")).unwrap(),
+            "\"\""
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
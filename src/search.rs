@@ -0,0 +1,248 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Outcome of a search from a single start node.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    distances: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    /// Cost of the cheapest path to every node that was reached.
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> HashMap<N, C> {
+        self.distances
+    }
+
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// The first goal node that was reached, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn goal_cost(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    /// Nodes on the cheapest path from the start to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distances.get(node)?;
+
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Nodes on the path from the start to the goal.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth-first search where every step costs 1. Stops at the first goal, use `|_| false` to
+/// visit everything reachable.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        distances: HashMap::from([(start.clone(), 0)]),
+        parents: HashMap::new(),
+        goal: None,
+    };
+
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        let distance = search.distances[&node];
+        for next in successors(&node) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance + 1);
+                search.parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+/// Cheapest paths with non-negative step costs. Stops at the first goal, use `|_| false` to
+/// visit everything reachable.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like `dijkstra`, guided by a heuristic that must never overestimate the remaining cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search {
+        distances: HashMap::from([(start.clone(), C::default())]),
+        parents: HashMap::new(),
+        goal: None,
+    };
+
+    let mut queue = BinaryHeap::new();
+    queue.push(Entry {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    });
+
+    while let Some(Entry { cost, node, .. }) = queue.pop() {
+        // Skip entries that were superseded by a cheaper path.
+        if search.distances[&node] < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if search.distances.get(&next).is_some_and(|&d| d <= next_cost) {
+                continue;
+            }
+
+            search.distances.insert(next.clone(), next_cost);
+            search.parents.insert(next.clone(), node.clone());
+            queue.push(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    search
+}
+
+/// Queue entry ordered by priority only, reversed so that `BinaryHeap` pops the cheapest first.
+struct Entry<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Weighted graph `0 -1- 1 -1- 2 -1- 3` with a shortcut `0 -5- 3` and an unreachable `4`.
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (3, 1)],
+            3 => vec![(2, 1), (0, 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let neighbours = |n: &u32| edges(n).into_iter().map(|(n, _)| n);
+
+        let search = bfs(0, neighbours, |&n| n == 3);
+        assert_eq!(search.goal(), Some(&3));
+        assert_eq!(search.goal_cost(), Some(1));
+        assert_eq!(search.goal_path(), Some(vec![0, 3]));
+
+        let search = bfs(0, neighbours, |_| false);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.distances().len(), 4);
+        assert_eq!(search.distance(&2), Some(2));
+        assert_eq!(search.distance(&4), None);
+        assert_eq!(search.path_to(&4), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let search = dijkstra(0, edges, |&n| n == 3);
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(search.goal_path(), Some(vec![0, 1, 2, 3]));
+
+        let search = dijkstra(0, edges, |&n| n == 4);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.distance(&3), Some(3));
+    }
+
+    #[test]
+    fn test_astar() {
+        // Manhattan distance on an open 10x10 grid.
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .map(|p| (p, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (9 - x) + (9 - y);
+
+        let search = astar((0, 0), successors, heuristic, |&p| p == (9, 9));
+        assert_eq!(search.goal_cost(), Some(18));
+        assert_eq!(search.goal_path().unwrap().len(), 19);
+    }
+}
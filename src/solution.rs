@@ -0,0 +1,29 @@
+use anyhow::Result;
+
+/// Answer to a single part, `None` while the part is not solved yet.
+pub type Answer = Option<String>;
+
+/// Solution of a puzzle, split into stages that can be run and timed independently.
+pub trait Solution {
+    /// The puzzle input after parsing, shared by both parts.
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(_input: &Self::Input) -> Result<Answer> {
+        Ok(None)
+    }
+
+    fn part2(_input: &Self::Input) -> Result<Answer> {
+        Ok(None)
+    }
+
+    /// Other implementations of the parts, e.g. a slower approach kept for comparison, named by
+    /// the stage they are benchmarked as. They are never run otherwise.
+    fn alternatives() -> Vec<(&'static str, Part<Self::Input>)> {
+        Vec::new()
+    }
+}
+
+/// Implementation of a single part.
+pub type Part<I> = fn(&I) -> Result<Answer>;
@@ -0,0 +1,35 @@
+pub mod y2015d01;
+pub mod y2015d03;
+pub mod y2015d04;
+pub mod y2015d05;
+pub mod y2015d06;
+pub mod y2015d07;
+pub mod y2015d08;
+pub mod y2015d09;
+pub mod y2015d10;
+pub mod y2015d11;
+pub mod y2015d12;
+pub mod y2015d13;
+pub mod y2015d14;
+pub mod y2015d15;
+pub mod y2015d16;
+pub mod y2015d17;
+pub mod y2015d18;
+pub mod y2015d19;
+pub mod y2015d20;
+pub mod y2015d21;
+pub mod y2015d22;
+pub mod y2015d23;
+pub mod y2015d24;
+pub mod y2015d25;
+pub mod y2016d01;
+pub mod y2018d23;
+pub mod y2019d18;
+pub mod y2019d22;
+pub mod y2022d19;
+pub mod y2024d01;
+pub mod y2024d03;
+pub mod y2024d08;
+pub mod y2024d20;
+pub mod y2024d22;
+pub mod y2024d25;
@@ -0,0 +1,39 @@
+use anyhow::Result;
+
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    /// Floor changes, `1` for up and `-1` for down.
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let moves = input
+            .chars()
+            .filter_map(|c| match c {
+                '(' => Some(1),
+                ')' => Some(-1),
+                _ => None,
+            })
+            .collect();
+
+        Ok(moves)
+    }
+
+    fn part1(moves: &Self::Input) -> Result<Answer> {
+        Ok(Some(moves.iter().sum::<i32>().to_string()))
+    }
+
+    fn part2(moves: &Self::Input) -> Result<Answer> {
+        let basement = moves
+            .iter()
+            .scan(0, |floor, m| {
+                *floor += m;
+                Some(*floor)
+            })
+            .position(|floor| floor == -1);
+
+        Ok(basement.map(|i| (i + 1).to_string()))
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::Result;
+
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+/// Step of a move as `(dx, dy)`.
+type Move = (i32, i32);
+
+impl Solution for Day {
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let line = input.trim();
+        line.chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                '^' => Ok((0, 1)),
+                'v' => Ok((0, -1)),
+                '>' => Ok((1, 0)),
+                '<' => Ok((-1, 0)),
+                _ => {
+                    let message = format!("Invalid direction: {}", c);
+                    Err(ParseError::at_line(1, line, message)
+                        .with_column(i + 1)
+                        .into())
+                }
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Some(q1(input).to_string()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Some(q2(input).to_string()))
+    }
+}
+
+fn q1(moves: &[Move]) -> usize {
+    let mut visited = std::collections::HashSet::new();
+
+    let mut x = 0;
+    let mut y = 0;
+
+    visited.insert((x, y));
+    for (dx, dy) in moves {
+        x += dx;
+        y += dy;
+        visited.insert((x, y));
+    }
+
+    visited.len()
+}
+
+fn q2(moves: &[Move]) -> usize {
+    let mut visited = std::collections::HashSet::new();
+
+    let mut x1 = 0;
+    let mut y1 = 0;
+    let mut x2 = 0;
+    let mut y2 = 0;
+    let mut santa_moves = true;
+
+    visited.insert((x1, y2));
+    for (dx, dy) in moves {
+        let (x, y) = if santa_moves {
+            (&mut x1, &mut y1)
+        } else {
+            (&mut x2, &mut y2)
+        };
+
+        santa_moves = !santa_moves;
+
+        *x += dx;
+        *y += dy;
+        visited.insert((*x, *y));
+    }
+
+    visited.len()
+}
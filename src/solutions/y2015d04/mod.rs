@@ -0,0 +1,33 @@
+use anyhow::{bail, Result};
+
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    /// The secret key.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input = input.trim();
+        if input.is_empty() {
+            bail!("Missing secret key");
+        }
+
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        for i in 0..i32::MAX {
+            let concatenated = format!("{}{}", input, i);
+            let hash = md5::compute(concatenated);
+
+            let enough_zeros = hash[0] == 0 && hash[1] == 0 && hash[2] < 16;
+            if enough_zeros {
+                return Ok(Some(i.to_string()));
+            }
+        }
+
+        bail!("No hash with five leading zeroes found")
+    }
+}
@@ -1,32 +1,26 @@
+use anyhow::Result;
 use itertools::Itertools;
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-fn main() -> std::io::Result<()> {
-    let path = env::current_dir()?.join("src/bin/y2015d5/input.txt");
-    let reader = BufReader::new(File::open(path)?);
+use crate::solution::{Answer, Solution};
 
-    let mut total = 0;
-    let mut total2 = 0;
+pub struct Day;
 
-    for line in reader.lines() {
-        let line = line?;
+impl Solution for Day {
+    type Input = Vec<String>;
 
-        if is_nice(&line) {
-            total += 1;
-        }
-
-        if is_nice2(&line) {
-            total2 += 1;
-        }
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
     }
 
-    println!("{}", total);
-    println!("{}", total2);
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        let total = lines.iter().filter(|line| is_nice(line)).count();
+        Ok(Some(total.to_string()))
+    }
 
-    Ok(())
+    fn part2(lines: &Self::Input) -> Result<Answer> {
+        let total = lines.iter().filter(|line| is_nice2(line)).count();
+        Ok(Some(total.to_string()))
+    }
 }
 
 fn is_nice(s: &str) -> bool {
@@ -0,0 +1,130 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::grid::Grid;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+const N: usize = 1000;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, str::parse)
+    }
+
+    fn part1(instructions: &Self::Input) -> Result<Answer> {
+        let mut lights = Grid::new(N, N, false);
+
+        for instruction in instructions {
+            for x in instruction.xrange.clone() {
+                for y in instruction.yrange.clone() {
+                    match instruction.operation {
+                        Operation::TurnOn => lights[(x, y)] = true,
+                        Operation::TurnOff => lights[(x, y)] = false,
+                        Operation::Toggle => lights[(x, y)] = !lights[(x, y)],
+                    }
+                }
+            }
+        }
+
+        let total_lights = lights.find_all(|&light| light).count();
+        Ok(Some(total_lights.to_string()))
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        let mut brightness = Grid::new(N, N, 0u8);
+
+        for instruction in instructions {
+            for x in instruction.xrange.clone() {
+                for y in instruction.yrange.clone() {
+                    let light = &mut brightness[(x, y)];
+                    match instruction.operation {
+                        Operation::TurnOn => *light += 1,
+                        Operation::TurnOff => *light = light.saturating_sub(1),
+                        Operation::Toggle => *light += 2,
+                    }
+                }
+            }
+        }
+
+        let total_brightness: u32 = brightness.iter().map(|(_, &b)| b as u32).sum();
+        Ok(Some(total_brightness.to_string()))
+    }
+}
+
+#[derive(Debug)]
+enum Operation {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    operation: Operation,
+    xrange: RangeInclusive<usize>,
+    yrange: RangeInclusive<usize>,
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let operation = match parts.next() {
+            Some("turn") => match parts.next() {
+                Some("on") => Operation::TurnOn,
+                Some("off") => Operation::TurnOff,
+                _ => bail!("Invalid operation"),
+            },
+            Some("toggle") => Operation::Toggle,
+            _ => bail!("Invalid operation"),
+        };
+
+        let (xstart, ystart) = parse_corner(parts.next())?;
+        if parts.next() != Some("through") {
+            bail!("Missing 'through' keyword");
+        }
+        let (xend, yend) = parse_corner(parts.next())?;
+
+        Ok(Instruction {
+            operation,
+            xrange: xstart..=xend,
+            yrange: ystart..=yend,
+        })
+    }
+}
+
+/// Parse an `x,y` corner inside the grid.
+fn parse_corner(corner: Option<&str>) -> Result<(usize, usize)> {
+    let corner = corner.ok_or(anyhow!("Missing corner"))?;
+    let (x, y) = corner
+        .split_once(',')
+        .ok_or(anyhow!("Invalid corner {}", corner))?;
+    let (x, y) = (x.parse()?, y.parse()?);
+
+    if x >= N || y >= N {
+        bail!("Corner {} is outside of the grid", corner);
+    }
+
+    Ok((x, y))
+}
+
+impl FromStr for Operation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "turnon" => Ok(Operation::TurnOn),
+            "turnoff" => Ok(Operation::TurnOff),
+            "toggle" => Ok(Operation::Toggle),
+            _ => bail!("Invalid operation"),
+        }
+    }
+}
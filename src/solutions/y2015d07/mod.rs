@@ -1,44 +1,47 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::str::FromStr;
 
-fn main() -> Result<()> {
-    let path = env::current_dir()?.join("src/bin/y2015d7/input.txt");
-    let reader = BufReader::new(File::open(path)?);
+use crate::parse;
+use crate::solution::{Answer, Solution};
 
-    let mut operations = HashMap::new();
+pub struct Day;
 
-    for line in reader.lines() {
-        let line = line?;
-        let assignment: Assignment = line.parse()?;
-        operations.insert(assignment.target, assignment.operation);
+impl Solution for Day {
+    /// Operation by target wire.
+    type Input = HashMap<String, Operation>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut operations = HashMap::new();
+
+        for assignment in parse::lines(input, str::parse::<Assignment>)? {
+            operations.insert(assignment.target, assignment.operation);
+        }
+
+        Ok(operations)
     }
 
-    let res = evaluate(
-        operations
-            .get("a")
-            .ok_or(anyhow::anyhow!("Key 'a' not found"))?,
-        &operations,
-        &mut HashMap::new(),
-    )?;
-    println!("Result in 'a': {}", res);
-
-    // Part2
-    operations.insert("b".to_string(), Operation::Literal(res));
-    let res = evaluate(
-        operations
-            .get("a")
-            .ok_or(anyhow::anyhow!("Key 'a' not found"))?,
-        &operations,
-        &mut HashMap::new(),
-    )?;
-    println!("Result in 'a' after 2nd iter: {}", res);
-
-    Ok(())
+    fn part1(operations: &Self::Input) -> Result<Answer> {
+        Ok(Some(signal_a(operations, &mut HashMap::new())?.to_string()))
+    }
+
+    fn part2(operations: &Self::Input) -> Result<Answer> {
+        let a = signal_a(operations, &mut HashMap::new())?;
+
+        // Override wire b with the signal of a, leaving the parsed circuit untouched.
+        let mut cache = HashMap::from([("b".to_string(), a)]);
+        Ok(Some(signal_a(operations, &mut cache)?.to_string()))
+    }
+}
+
+fn signal_a(
+    operations: &HashMap<String, Operation>,
+    cache: &mut HashMap<String, u16>,
+) -> Result<u16> {
+    let a = operations
+        .get("a")
+        .ok_or(anyhow::anyhow!("Key 'a' not found"))?;
+    evaluate(a, operations, cache)
 }
 
 fn evaluate(
@@ -85,7 +88,7 @@ fn evaluate(
 }
 
 #[derive(Debug)]
-enum Operation {
+pub enum Operation {
     Literal(u16),
     Variable(String),
     Not(Box<Operation>),
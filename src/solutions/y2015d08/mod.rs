@@ -1,38 +1,31 @@
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
-
 use anyhow::Result;
 
-fn main() -> Result<()> {
-    let path = env::current_dir()?.join("src/bin/y2015d8/input.txt");
-    let reader = BufReader::new(File::open(path)?);
-
-    let mut total_chars = 0;
-    let mut total_symbols = 0;
-    let mut total_encoded = 0;
+use crate::solution::{Answer, Solution};
 
-    for line in reader.lines() {
-        let line = line?;
+pub struct Day;
 
-        let chars = line.len();
-        let symbols = count_symbols(&line);
-        let encoded = encode(&line).as_str().len();
+impl Solution for Day {
+    type Input = Vec<String>;
 
-        total_chars += chars;
-        total_symbols += symbols;
-        total_encoded += encoded;
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
     }
 
-    println!("Total chars: {}", total_chars);
-    println!("Total symbols: {}", total_symbols);
-    println!("Difference: {}", total_chars - total_symbols);
-
-    println!("Total encoded: {}", total_encoded);
-    println!("Difference: {}", total_encoded - total_chars);
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        let difference: usize = lines
+            .iter()
+            .map(|line| line.len() - count_symbols(line))
+            .sum();
+        Ok(Some(difference.to_string()))
+    }
 
-    Ok(())
+    fn part2(lines: &Self::Input) -> Result<Answer> {
+        let difference: usize = lines
+            .iter()
+            .map(|line| encode(line).len() - line.len())
+            .sum();
+        Ok(Some(difference.to_string()))
+    }
 }
 
 fn count_symbols(s: &str) -> usize {
@@ -0,0 +1,107 @@
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+
+use crate::cancel;
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+/// The places and the distances between them, in both directions.
+pub struct Distances {
+    places: Vec<String>,
+    distances: HashMap<(String, String), i32>,
+}
+
+impl Distances {
+    fn between(&self, a: &str, b: &str) -> Result<i32> {
+        match self.distances.get(&(a.to_string(), b.to_string())) {
+            Some(&dist) => Ok(dist),
+            None => bail!("No distance between {} and {}", a, b),
+        }
+    }
+
+    /// Lengths of all the routes visiting every place once.
+    fn routes(&self) -> Result<Vec<i32>> {
+        self.places
+            .iter()
+            .permutations(self.places.len())
+            .map(|perm| {
+                cancel::check()?;
+
+                perm.iter()
+                    .tuple_windows()
+                    .map(|(a, b)| self.between(a, b))
+                    .sum::<Result<i32>>()
+            })
+            .collect()
+    }
+}
+
+impl Solution for Day {
+    type Input = Distances;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut places = BTreeSet::new();
+        let mut distances = HashMap::new();
+
+        let edges = parse::lines(input, |line| {
+            let parts = line.split_whitespace().collect_vec();
+            if parts.len() != 5 {
+                bail!("Unable to match {}", line);
+            }
+
+            Ok((parts[0], parts[2], parts[4].parse::<i32>()?))
+        })?;
+
+        for (src, dest, dist) in edges {
+            places.insert(src.to_string());
+            places.insert(dest.to_string());
+            distances.insert((src.to_string(), dest.to_string()), dist);
+            distances.insert((dest.to_string(), src.to_string()), dist);
+        }
+
+        let places = places.into_iter().collect_vec();
+        if places.len() < 2 {
+            bail!("Expected at least 2 places, got {}", places.len());
+        }
+
+        Ok(Distances { places, distances })
+    }
+
+    fn part1(distances: &Self::Input) -> Result<Answer> {
+        let min = distances
+            .routes()?
+            .into_iter()
+            .min()
+            .ok_or(anyhow!("Need at least one route"))?;
+        Ok(Some(min.to_string()))
+    }
+
+    fn part2(distances: &Self::Input) -> Result<Answer> {
+        let max = distances
+            .routes()?
+            .into_iter()
+            .max()
+            .ok_or(anyhow!("Need at least one route"))?;
+        Ok(Some(max.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let distances = Day::parse("A to B = 3\nB to C = 4\nA to C = 6\n").unwrap();
+        assert_eq!(distances.routes().unwrap().into_iter().min(), Some(7));
+        assert!(Day::parse("").is_err());
+
+        // Every pair of places needs a distance.
+        let distances = Day::parse("A to B = 3\nB to C = 4\n").unwrap();
+        assert!(distances.routes().is_err());
+    }
+}
@@ -0,0 +1,44 @@
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use std::fmt::Write;
+
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input = input.trim();
+        if input.is_empty() || !input.bytes().all(|b| b.is_ascii_digit()) {
+            bail!("Expected a sequence of digits, got {:?}", input);
+        }
+
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer> {
+        Ok(Some(repeat(input, 40)?.len().to_string()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer> {
+        Ok(Some(repeat(input, 50)?.len().to_string()))
+    }
+}
+
+fn repeat(input: &str, times: usize) -> Result<String> {
+    let mut result = input.to_string();
+    for _ in 0..times {
+        result = look_and_say(&result)?;
+    }
+    Ok(result)
+}
+
+fn look_and_say(input: &str) -> Result<String> {
+    let mut buffer = String::with_capacity(2 * input.len());
+    for (c, chunk) in &input.chars().chunk_by(|c| *c) {
+        write!(buffer, "{}{}", chunk.count(), c)?
+    }
+    Ok(buffer)
+}
@@ -0,0 +1,94 @@
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let password = input.trim();
+        if password.is_empty() || !password.bytes().all(|b| b.is_ascii_lowercase()) {
+            bail!("Expected a lowercase password, got {:?}", password);
+        }
+        // A straight of three and two pairs need at least five letters, e.g. "aabcc".
+        if password.len() < 5 {
+            bail!("The password {:?} is too short to ever be valid", password);
+        }
+
+        Ok(password.to_string())
+    }
+
+    fn part1(password: &Self::Input) -> Result<Answer> {
+        Ok(Some(next_valid(password.clone())?))
+    }
+
+    fn part2(password: &Self::Input) -> Result<Answer> {
+        Ok(Some(next_valid(next_valid(password.clone())?)?))
+    }
+}
+
+fn next_valid(password: String) -> Result<String> {
+    let mut next = password.clone();
+    loop {
+        next = increment(next).ok_or(anyhow!("No valid password after {}", password))?;
+        if is_valid(next.as_str()) {
+            return Ok(next);
+        }
+    }
+}
+
+/// The next password of the same length, `None` after the last one, all `z`.
+fn increment(s: String) -> Option<String> {
+    let mut s = s.into_bytes();
+    let mut i = s.len().checked_sub(1)?;
+    loop {
+        if s[i] == b'z' {
+            s[i] = b'a';
+            i = i.checked_sub(1)?;
+        } else {
+            s[i] += 1;
+            break;
+        }
+    }
+    String::from_utf8(s).ok()
+}
+
+fn is_valid(s: &str) -> bool {
+    // Rule 2
+    if s.bytes().any(|c| c == b'i' || c == b'o' || c == b'l') {
+        return false;
+    }
+
+    // Rule 1
+    if !s
+        .bytes()
+        .tuple_windows()
+        .any(|(a, b, c)| a == b - 1 && a == c - 2)
+    {
+        return false;
+    }
+
+    // Rule 3
+    let first = s.bytes().tuple_windows().find_position(|(a, b)| a == b);
+    if let Some((i, _)) = first {
+        s.bytes().tuple_windows().skip(i + 2).any(|(a, b)| a == b)
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_valid() {
+        assert_eq!(next_valid("abcdefgh".into()).unwrap(), "abcdffaa");
+        assert_eq!(increment("zz".into()), None);
+        assert!(next_valid("zzzzz".into()).is_err());
+        assert!(Day::parse("x").is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Value;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        serde_json::from_str(input).map_err(|e| {
+            let line = input
+                .lines()
+                .nth(e.line().saturating_sub(1))
+                .unwrap_or_default();
+            // The message would repeat the position otherwise.
+            let message = e.to_string();
+            let message = message.split(" at line ").next().unwrap_or_default();
+            ParseError::at_line(e.line(), line, message)
+                .with_column(e.column())
+                .into()
+        })
+    }
+
+    fn part2(v: &Self::Input) -> Result<Answer> {
+        Ok(Some(sum_json(v).to_string()))
+    }
+}
+
+fn sum_json(v: &Value) -> i64 {
+    match v {
+        Value::Number(number) => number.as_i64().unwrap(),
+        Value::Array(array) => array.iter().map(sum_json).sum(),
+        Value::Object(object) => {
+            if object
+                .values()
+                .any(|v| v.as_str().is_some_and(|s| s == "red"))
+            {
+                0
+            } else {
+                object.values().map(sum_json).sum()
+            }
+        }
+        _ => 0,
+    }
+}
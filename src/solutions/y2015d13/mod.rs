@@ -0,0 +1,104 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use crate::cancel;
+use crate::parse::{self, regex_struct};
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Table;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut table = Table::default();
+
+        for scoring in parse::lines(input, str::parse::<Scoring>)? {
+            let change = scoring.change();
+            table.subjects.insert(scoring.subject.clone());
+            table
+                .scorings
+                .insert((scoring.subject, scoring.neighbour), change);
+        }
+
+        Ok(table)
+    }
+
+    fn part2(table: &Self::Input) -> Result<Answer> {
+        let mut subjects = table.subjects.clone();
+        subjects.insert("You".into());
+
+        let scores = subjects.iter().permutations(subjects.len()).map(|perm| {
+            cancel::check()?;
+
+            let score = perm
+                .iter()
+                .circular_tuple_windows()
+                .map(|(&left, &subject, &right)| {
+                    let left = table
+                        .scorings
+                        .get(&(subject.to_string(), left.to_string()))
+                        .unwrap_or(&0);
+                    let right = table
+                        .scorings
+                        .get(&(subject.to_string(), right.to_string()))
+                        .unwrap_or(&0);
+                    left + right
+                })
+                .sum::<i32>();
+            Ok::<_, anyhow::Error>(score)
+        });
+
+        let max_score = itertools::process_results(scores, |scores| scores.max())?
+            .ok_or(anyhow!("Nobody to seat"))?;
+
+        Ok(Some(max_score.to_string()))
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Table {
+    subjects: HashSet<String>,
+    /// Change in happiness of a subject by their neighbour.
+    scorings: HashMap<(String, String), i32>,
+}
+
+regex_struct! {
+    #[regex = r"^(?<subject>.+) would (?<effect>gain|lose) (?<units>\d+) happiness units by sitting next to (?<neighbour>.+)\.$"]
+    #[derive(Debug)]
+    struct Scoring {
+        subject: String,
+        effect: Effect,
+        units: i32,
+        neighbour: String,
+    }
+}
+
+impl Scoring {
+    fn change(&self) -> i32 {
+        match self.effect {
+            Effect::Gain => self.units,
+            Effect::Lose => -self.units,
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Effect {
+    Gain,
+    Lose,
+}
+
+impl FromStr for Effect {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "gain" => Ok(Effect::Gain),
+            "lose" => Ok(Effect::Lose),
+            _ => anyhow::bail!("Unknown value of {}", s),
+        }
+    }
+}
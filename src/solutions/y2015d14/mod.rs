@@ -0,0 +1,90 @@
+use anyhow::Result;
+
+use crate::parse::{self, regex_struct};
+use crate::solution::{Answer, Solution};
+
+const RACE_DURATION: i32 = 2503;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, str::parse)
+    }
+
+    fn part1(reindeers: &Self::Input) -> Result<Answer> {
+        let max_distance = reindeers
+            .iter()
+            .map(|reindeer| {
+                let cycle_duration = reindeer.flying_time + reindeer.rest_time;
+                let cycles = RACE_DURATION / cycle_duration;
+                let reminder = RACE_DURATION % cycle_duration;
+
+                reindeer.speed
+                    * (reindeer.flying_time * cycles + reindeer.flying_time.min(reminder))
+            })
+            .max()
+            .unwrap_or(0);
+
+        Ok(Some(max_distance.to_string()))
+    }
+
+    fn part2(reindeers: &Self::Input) -> Result<Answer> {
+        let mut states: Vec<_> = reindeers
+            .iter()
+            .map(|reindeer| State::Fly(reindeer.flying_time))
+            .collect();
+        let mut scores = vec![0; reindeers.len()];
+        let mut locations = vec![0; reindeers.len()];
+
+        for i in 1..=RACE_DURATION {
+            for ((reindeer, state), location) in reindeers
+                .iter()
+                .zip(states.iter_mut())
+                .zip(locations.iter_mut())
+            {
+                match state {
+                    State::Fly(until) => {
+                        *location += reindeer.speed;
+
+                        if *until == i {
+                            *state = State::Rest(i + reindeer.rest_time);
+                        }
+                    }
+                    State::Rest(until) => {
+                        if *until == i {
+                            *state = State::Fly(i + reindeer.flying_time);
+                        }
+                    }
+                }
+            }
+
+            let max_loc = *locations.iter().max().unwrap_or(&0);
+            for (location, score) in locations.iter().zip(scores.iter_mut()) {
+                if *location == max_loc {
+                    *score += 1;
+                }
+            }
+        }
+
+        let max_score = scores.iter().max().unwrap_or(&0);
+        Ok(Some(max_score.to_string()))
+    }
+}
+
+enum State {
+    Fly(i32),
+    Rest(i32),
+}
+
+regex_struct! {
+    #[regex = r"^.+ can fly (?<speed>\d+) km/s for (?<flying_time>\d+) seconds, but then must rest for (?<rest_time>\d+) seconds\.$"]
+    #[derive(Debug)]
+    pub struct Reindeer {
+        speed: i32,
+        flying_time: i32,
+        rest_time: i32,
+    }
+}
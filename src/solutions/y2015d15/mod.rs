@@ -0,0 +1,82 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+use crate::cancel;
+use crate::log;
+use crate::parse::{self, regex_struct};
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Ingredient>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let ingredients = parse::lines(input, str::parse::<Ingredient>)?;
+        for ingredient in &ingredients {
+            log::debug!("{:?}", ingredient);
+        }
+
+        Ok(ingredients)
+    }
+
+    fn part2(ingredients: &Self::Input) -> Result<Answer> {
+        Ok(Some(best_cookie(ingredients)?.to_string()))
+    }
+}
+
+fn best_cookie(ingredients: &[Ingredient]) -> Result<i32> {
+    // This is very inefficient way to get all the combinations.
+    let combinations = (0..ingredients.len())
+        .map(|_| 1..=100)
+        .multi_cartesian_product()
+        .filter(|amounts| amounts.iter().sum::<i32>() == 100)
+        .map(|amounts| cancel::check().map(|_| amounts));
+
+    let max = itertools::process_results(combinations, |combinations| {
+        combinations
+            .filter_map(|amounts| {
+                let mut capacity = 0;
+                let mut durability = 0;
+                let mut flavor = 0;
+                let mut texture = 0;
+                let mut calories = 0;
+
+                for (amount, ingredient) in amounts.iter().zip(ingredients) {
+                    capacity += amount * ingredient.capacity;
+                    durability += amount * ingredient.durability;
+                    flavor += amount * ingredient.flavor;
+                    texture += amount * ingredient.texture;
+                    calories += amount * ingredient.calories;
+                }
+
+                capacity = capacity.max(0);
+                durability = durability.max(0);
+                flavor = flavor.max(0);
+                texture = texture.max(0);
+                calories = calories.max(0);
+
+                if calories == 500 {
+                    Some(capacity * durability * flavor * texture)
+                } else {
+                    None
+                }
+            })
+            .max()
+    })?
+    .ok_or(anyhow!("No cookie with 500 calories"))?;
+
+    Ok(max)
+}
+
+regex_struct! {
+    #[regex = r"^.+: capacity (?<capacity>-?\d+), durability (?<durability>-?\d+), flavor (?<flavor>-?\d+), texture (?<texture>-?\d+), calories (?<calories>-?\d+)$"]
+    #[derive(Debug)]
+    pub struct Ingredient {
+        capacity: i32,
+        durability: i32,
+        flavor: i32,
+        texture: i32,
+        calories: i32,
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::{bail, Result};
+use regex::Regex;
+
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Sue>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = Regex::new(r"^Sue (.+): (.+): (\d+), (.+): (\d+), (.+): (\d+)$").unwrap();
+
+        parse::lines(input, |line| {
+            let cap = re
+                .captures(line)
+                .ok_or(anyhow::anyhow!("Unable to match {}", line))?;
+
+            let mut compounds = Vec::new();
+            for i in (2..=6).step_by(2) {
+                compounds.push((cap[i].to_string(), cap[i + 1].parse()?));
+            }
+
+            Ok(Sue {
+                number: cap[1].to_string(),
+                compounds,
+            })
+        })
+    }
+
+    fn part2(sues: &Self::Input) -> Result<Answer> {
+        Ok(Some(find_sue(sues)?.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Sue {
+    number: String,
+    compounds: Vec<(String, i32)>,
+}
+
+fn find_sue(sues: &[Sue]) -> Result<&str> {
+    let mut measurements = std::collections::HashMap::new();
+    measurements.insert("children", Amount::Exact(3));
+    measurements.insert("cats", Amount::More(7));
+    measurements.insert("samoyeds", Amount::Exact(2));
+    measurements.insert("pomeranians", Amount::Less(3));
+    measurements.insert("akitas", Amount::Exact(0));
+    measurements.insert("vizslas", Amount::Exact(0));
+    measurements.insert("goldfish", Amount::Less(5));
+    measurements.insert("trees", Amount::More(3));
+    measurements.insert("cars", Amount::Exact(2));
+    measurements.insert("perfumes", Amount::Exact(1));
+
+    'outer: for sue in sues {
+        for (compound, compound_amount) in &sue.compounds {
+            let amount = measurements
+                .get(compound.as_str())
+                .ok_or(anyhow::anyhow!("Unknown compound {}", compound))?;
+
+            let matches = match *amount {
+                Amount::Exact(v) => *compound_amount == v,
+                Amount::Less(v) => *compound_amount < v,
+                Amount::More(v) => *compound_amount > v,
+            };
+
+            if !matches {
+                continue 'outer;
+            }
+        }
+
+        return Ok(&sue.number);
+    }
+
+    bail!("No Sue matches")
+}
+
+enum Amount {
+    Exact(i32),
+    Less(i32),
+    More(i32),
+}
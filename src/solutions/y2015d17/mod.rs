@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    /// Container sizes.
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, |l| Ok(l.parse()?))
+    }
+
+    fn part1(containers: &Self::Input) -> Result<Answer> {
+        let total = combinations(containers).values().sum::<usize>();
+        Ok(Some(total.to_string()))
+    }
+
+    fn part2(containers: &Self::Input) -> Result<Answer> {
+        let c = combinations(containers);
+        let fewest_containers = c.keys().min().map_or(0, |len| c[len]);
+        Ok(Some(fewest_containers.to_string()))
+    }
+}
+
+/// Number of combinations that fit exactly 150 liters, by number of containers.
+fn combinations(containers: &[i32]) -> HashMap<usize, usize> {
+    containers
+        .iter()
+        .powerset()
+        .filter(|subset| subset.iter().copied().sum::<i32>() == 150)
+        .counts_by(|subset| subset.len())
+}
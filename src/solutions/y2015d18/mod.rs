@@ -0,0 +1,59 @@
+use anyhow::{bail, Result};
+
+use crate::grid::Grid;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lights = Grid::parse(input, |c| match c {
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => bail!("Invalid light {}", c),
+        })?;
+
+        if lights.width() == 0 || lights.height() == 0 {
+            bail!("Expected at least one light");
+        }
+
+        Ok(lights)
+    }
+
+    fn part2(lights: &Self::Input) -> Result<Answer> {
+        Ok(Some(animate(lights.clone()).to_string()))
+    }
+}
+
+/// Run 100 steps with the corners stuck on and count the lights that are on.
+fn animate(mut lights: Grid<bool>) -> usize {
+    let (last_row, last_col) = (lights.height() - 1, lights.width() - 1);
+    let corners = [(0, 0), (0, last_col), (last_row, 0), (last_row, last_col)];
+
+    for corner in corners {
+        lights[corner] = true;
+    }
+
+    for _ in 0..100 {
+        let mut neighbours = lights.map(|_| 0u8);
+        for pos in lights.positions() {
+            neighbours[pos] = lights.neighbours8(pos).filter(|&p| lights[p]).count() as u8;
+        }
+
+        for pos in lights.positions() {
+            if lights[pos] {
+                lights[pos] = neighbours[pos] == 2 || neighbours[pos] == 3
+            } else {
+                lights[pos] = neighbours[pos] == 3
+            }
+        }
+
+        for corner in corners {
+            lights[corner] = true;
+        }
+    }
+
+    lights.find_all(|&light| light).count()
+}
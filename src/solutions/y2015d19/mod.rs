@@ -0,0 +1,155 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{bail, Result};
+use regex::Regex;
+
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Machine;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = input.lines();
+
+        let re = Regex::new(r"^(.+) => (.+)$").unwrap();
+
+        let mut replacements = HashMap::new();
+
+        for (i, line) in lines.by_ref().enumerate() {
+            if line.is_empty() {
+                break;
+            }
+
+            let captures = re
+                .captures(line)
+                .ok_or_else(|| ParseError::at_line(i + 1, line, "Expected a replacement"))?;
+
+            replacements
+                .entry(captures[1].to_string())
+                .or_insert_with(Vec::new)
+                .push(captures[2].to_string());
+        }
+
+        let target_molecule = lines
+            .next()
+            .ok_or(anyhow::anyhow!("Missing target molecule"))?
+            .to_string();
+
+        Ok(Machine {
+            replacements,
+            target_molecule,
+        })
+    }
+
+    fn part1(machine: &Self::Input) -> Result<Answer> {
+        let mut new_molecules = HashSet::new();
+        run_replacement_step(
+            &machine.target_molecule,
+            &machine.replacements,
+            &mut new_molecules,
+        );
+
+        Ok(Some(new_molecules.len().to_string()))
+    }
+
+    fn part2(machine: &Self::Input) -> Result<Answer> {
+        Ok(Some(fabricate(machine)?.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Machine {
+    replacements: HashMap<String, Vec<String>>,
+    target_molecule: String,
+}
+
+/// Restarts of the greedy reduction before giving up.
+const ATTEMPTS: usize = 1000;
+
+/// Steps to make the target molecule from `e`, found by greedily undoing replacements, longest
+/// first. Greedy reduction can get stuck, it then restarts with the replacements shuffled. The
+/// shuffles are seeded, so the result is the same on every run.
+fn fabricate(machine: &Machine) -> Result<usize> {
+    let mut reverse_replacements = machine
+        .replacements
+        .iter()
+        .flat_map(|(k, vs)| vs.iter().map(move |v| (v.clone(), k.clone())))
+        .collect::<Vec<(String, String)>>();
+    reverse_replacements.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then(a.cmp(b)));
+
+    let mut seed = 0x2015_0019;
+    for _ in 0..ATTEMPTS {
+        if let Some(steps) = reduce(&machine.target_molecule, &reverse_replacements) {
+            return Ok(steps);
+        }
+        shuffle(&mut reverse_replacements, &mut seed);
+    }
+
+    bail!(
+        "No way found to fabricate {} in {} attempts",
+        machine.target_molecule,
+        ATTEMPTS
+    );
+}
+
+/// Apply the first matching replacement until only `e` is left, `None` if it gets stuck.
+fn reduce(molecule: &str, reverse_replacements: &[(String, String)]) -> Option<usize> {
+    let mut steps = 0;
+    let mut current_molecule = molecule.to_string();
+
+    while current_molecule != "e" {
+        let (k, v) = reverse_replacements
+            .iter()
+            .find(|(k, _)| current_molecule.contains(k.as_str()))?;
+        current_molecule = current_molecule.replacen(k.as_str(), v, 1);
+        steps += 1;
+    }
+
+    Some(steps)
+}
+
+/// Fisher-Yates shuffle with a xorshift generator, to stay reproducible.
+fn shuffle<T>(items: &mut [T], seed: &mut u64) {
+    for i in (1..items.len()).rev() {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        items.swap(i, (*seed % (i as u64 + 1)) as usize);
+    }
+}
+
+fn run_replacement_step(
+    starting_molecule: &str,
+    replacements: &HashMap<String, Vec<String>>,
+    new_molecules: &mut HashSet<String>,
+) {
+    for (k, vs) in replacements {
+        let klen = k.chars().count();
+        for (index, _) in starting_molecule.match_indices(k) {
+            for v in vs {
+                let mut clone = starting_molecule.to_owned();
+                clone.replace_range(index..index + klen, v);
+
+                new_molecules.insert(clone);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fabricate() {
+        let machine = Day::parse("e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOH\n").unwrap();
+        assert_eq!(fabricate(&machine).unwrap(), 3);
+
+        // Nothing makes the molecule, every attempt gets stuck.
+        let machine = Day::parse("e => H\nH => HO\n\nOH\n").unwrap();
+        assert!(fabricate(&machine).is_err());
+    }
+}
@@ -1,8 +1,23 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
-fn main() -> Result<()> {
-    let goal_count = 33_100_000;
+use crate::solution::{Answer, Solution};
 
+pub struct Day;
+
+impl Solution for Day {
+    /// Number of presents the house should get.
+    type Input = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().parse()?)
+    }
+
+    fn part2(goal_count: &Self::Input) -> Result<Answer> {
+        Ok(Some(first_house(*goal_count)?.to_string()))
+    }
+}
+
+fn first_house(goal_count: i32) -> Result<i32> {
     let first_house = (1..).find(|&i| {
         let mut present_count = 0;
         let sqrt = (i as f64).sqrt() as i32;
@@ -27,7 +42,5 @@ fn main() -> Result<()> {
         present_count >= goal_count
     });
 
-    println!("{:?}", first_house);
-
-    Ok(())
+    first_house.ok_or(anyhow!("No house gets enough presents"))
 }
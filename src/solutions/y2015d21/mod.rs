@@ -1,9 +1,27 @@
 use std::ops::AddAssign;
+use std::str::FromStr;
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use itertools::{iproduct, Itertools};
 
-fn main() -> Result<()> {
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Character;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(boss: &Self::Input) -> Result<Answer> {
+        Ok(Some(cheapest_win(*boss)?.to_string()))
+    }
+}
+
+fn cheapest_win(boss: Character) -> Result<i32> {
     let weapons = [
         Item::offensive(8, 4),
         Item::offensive(10, 5),
@@ -47,14 +65,7 @@ fn main() -> Result<()> {
         .sorted_by_key(|i| i.cost)
         .find(|i| {
             let mut me = Character::new(100, *i);
-            let mut boss = Character::new(
-                108,
-                Item {
-                    cost: 0,
-                    damage: 8,
-                    armor: 2,
-                },
-            );
+            let mut boss = boss;
 
             loop {
                 me.attack(&boss);
@@ -71,9 +82,8 @@ fn main() -> Result<()> {
             }
         });
 
-    println!("Cheapest option: {:?}", cheapest);
-
-    Ok(())
+    let cheapest = cheapest.ok_or(anyhow!("No equipment beats the boss"))?;
+    Ok(cheapest.cost)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -111,7 +121,8 @@ impl AddAssign for Item {
     }
 }
 
-struct Character {
+#[derive(Debug, Clone, Copy)]
+pub struct Character {
     hp: i32,
     equipment: Item,
 }
@@ -125,3 +136,38 @@ impl Character {
         self.hp -= (other.equipment.damage - self.equipment.armor).max(1);
     }
 }
+
+impl FromStr for Character {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut hp = None;
+        let mut damage = None;
+        let mut armor = None;
+
+        let stats = parse::lines(s, |line| {
+            let (stat, value) = line
+                .split_once(": ")
+                .ok_or(anyhow!("Unable to match {}", line))?;
+            Ok((stat, value.parse()?))
+        })?;
+
+        for (stat, value) in stats {
+            match stat {
+                "Hit Points" => hp = Some(value),
+                "Damage" => damage = Some(value),
+                "Armor" => armor = Some(value),
+                _ => bail!("Unknown stat {}", stat),
+            }
+        }
+
+        Ok(Character::new(
+            hp.ok_or(anyhow!("Missing Hit Points"))?,
+            Item {
+                cost: 0,
+                damage: damage.ok_or(anyhow!("Missing Damage"))?,
+                armor: armor.ok_or(anyhow!("Missing Armor"))?,
+            },
+        ))
+    }
+}
@@ -1,4 +1,10 @@
-use anyhow::Result;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::cancel;
+use crate::parse;
+use crate::solution::{Answer, Solution};
 
 const SPELLS: [Spell; 5] = [
     Spell {
@@ -47,33 +53,44 @@ const SPELLS: [Spell; 5] = [
         armor: 0,
     },
 ];
-fn main() -> Result<()> {
-    let player = Character::player();
-    let boss = Character::boss();
-    let active_spells: Vec<Spell> = Vec::new();
 
-    let result = players_turn(player, boss, active_spells);
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Character;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part2(boss: &Self::Input) -> Result<Answer> {
+        let player = Character::player();
+        let active_spells: Vec<Spell> = Vec::new();
 
-    println!("{:?}", result);
+        let result = players_turn(player, *boss, active_spells)?
+            .ok_or(anyhow!("The boss can not be beaten"))?;
 
-    Ok(())
+        Ok(Some(result.to_string()))
+    }
 }
 
 fn players_turn(
     mut player: Character,
     mut boss: Character,
     active_spells: Vec<Spell>,
-) -> Option<i32> {
+) -> Result<Option<i32>> {
+    cancel::check()?;
+
     // hard mode
     player.hp -= 1;
     if player.hp <= 0 {
-        return None;
+        return Ok(None);
     }
 
     let active_spells: Vec<Spell> = activate_spells(&mut player, &mut boss, active_spells);
 
     if boss.hp <= 0 {
-        return Some(0);
+        return Ok(Some(0));
     }
 
     let mut min_cost: Option<i32> = None;
@@ -94,33 +111,33 @@ fn players_turn(
         new_active_spells.push(spell);
 
         let cost =
-            bosses_turn(cloned_player, boss, new_active_spells).map(|cost| cost + spell.cost);
+            bosses_turn(cloned_player, boss, new_active_spells)?.map(|cost| cost + spell.cost);
         min_cost = match (min_cost, cost) {
             (Some(a), Some(b)) => Some(a.min(b)),
             (a, b) => a.or(b),
         }
     }
 
-    min_cost
+    Ok(min_cost)
 }
 
 fn bosses_turn(
     mut player: Character,
     mut boss: Character,
     active_spells: Vec<Spell>,
-) -> Option<i32> {
+) -> Result<Option<i32>> {
     player.armor = 0;
 
     let active_spells: Vec<Spell> = activate_spells(&mut player, &mut boss, active_spells);
 
     if boss.hp <= 0 {
-        return Some(0);
+        return Ok(Some(0));
     }
 
     player.hp -= (boss.damage - player.armor).max(1);
 
     if player.hp <= 0 {
-        return None;
+        return Ok(None);
     }
 
     players_turn(player, boss, active_spells)
@@ -170,7 +187,7 @@ struct Spell {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Character {
+pub struct Character {
     hp: i32,
     armor: i32,
     damage: i32,
@@ -186,13 +203,35 @@ impl Character {
             mana: 500,
         }
     }
+}
 
-    fn boss() -> Self {
-        Self {
-            hp: 71,
+impl FromStr for Character {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut hp = None;
+        let mut damage = None;
+
+        let stats = parse::lines(s, |line| {
+            let (stat, value) = line
+                .split_once(": ")
+                .ok_or(anyhow!("Unable to match {}", line))?;
+            Ok((stat, value.parse()?))
+        })?;
+
+        for (stat, value) in stats {
+            match stat {
+                "Hit Points" => hp = Some(value),
+                "Damage" => damage = Some(value),
+                _ => bail!("Unknown stat {}", stat),
+            }
+        }
+
+        Ok(Self {
+            hp: hp.ok_or(anyhow!("Missing Hit Points"))?,
             armor: 0,
-            damage: 10,
+            damage: damage.ok_or(anyhow!("Missing Damage"))?,
             mana: 0,
-        }
+        })
     }
 }
@@ -0,0 +1,145 @@
+use anyhow::{anyhow, bail, Result};
+use std::str::FromStr;
+
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, str::parse)
+    }
+
+    fn part2(instructions: &Self::Input) -> Result<Answer> {
+        Ok(Some(execute(instructions, [1, 0])[1].to_string()))
+    }
+}
+
+/// Run the program with the given initial registers and return the final registers.
+fn execute(instructions: &[Instruction], mut registers: [u64; 2]) -> [u64; 2] {
+    let mut loc: i32 = 0;
+
+    loop {
+        match instructions.get(loc as usize) {
+            None => break,
+            Some(Instruction::Hlf(r)) => {
+                registers[*r] /= 2;
+                loc += 1;
+            }
+            Some(Instruction::Tpl(r)) => {
+                registers[*r] *= 3;
+                loc += 1;
+            }
+            Some(Instruction::Inc(r)) => {
+                registers[*r] += 1;
+                loc += 1;
+            }
+            Some(Instruction::Jmp(offset)) => {
+                loc += offset;
+            }
+            Some(Instruction::Jie(r, offset)) => {
+                if registers[*r].is_multiple_of(2) {
+                    loc += offset;
+                } else {
+                    loc += 1;
+                }
+            }
+            Some(Instruction::Jio(r, offset)) => {
+                if registers[*r] == 1 {
+                    loc += offset;
+                } else {
+                    loc += 1;
+                }
+            }
+        }
+    }
+
+    registers
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    /// Instructions name registers by index, `a` is 0 and `b` is 1.
+    Hlf(usize),
+    Tpl(usize),
+    Inc(usize),
+    Jmp(i32),
+    Jie(usize, i32),
+    Jio(usize, i32),
+}
+
+impl FromStr for Instruction {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (op, args) = s.split_once(' ').ok_or(anyhow!("Unable to match {}", s))?;
+        let start = op.len() + 1;
+
+        let i = match op {
+            "hlf" => Instruction::Hlf(register(s, start, args)?),
+            "tpl" => Instruction::Tpl(register(s, start, args)?),
+            "inc" => Instruction::Inc(register(s, start, args)?),
+            "jmp" => Instruction::Jmp(offset(s, start, args)?),
+            "jie" | "jio" => {
+                let (r, o) = args
+                    .split_once(", ")
+                    .ok_or(anyhow!("Expected a register and an offset in {}", s))?;
+                let o = offset(s, start + r.len() + 2, o)?;
+                let r = register(s, start, r)?;
+                if op == "jie" {
+                    Instruction::Jie(r, o)
+                } else {
+                    Instruction::Jio(r, o)
+                }
+            }
+            _ => bail!("Unknown instruction {}", op),
+        };
+
+        Ok(i)
+    }
+}
+
+/// Index of the register named at byte `start` of `line`.
+fn register(line: &str, start: usize, name: &str) -> Result<usize> {
+    match name {
+        "a" => Ok(0),
+        "b" => Ok(1),
+        _ => Err(error_at(
+            line,
+            start,
+            format!("Unknown register {:?}", name),
+        )),
+    }
+}
+
+/// Jump offset at byte `start` of `line`.
+fn offset(line: &str, start: usize, offset: &str) -> Result<i32> {
+    offset
+        .parse()
+        .map_err(|e| error_at(line, start, format!("Invalid offset {:?}: {}", offset, e)))
+}
+
+fn error_at(line: &str, start: usize, message: String) -> anyhow::Error {
+    let column = line[..start].chars().count() + 1;
+    ParseError::new(message).with_column(column).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let instructions = Day::parse("inc a\njio a, +2\ntpl b\njmp -7\n").unwrap();
+        assert_eq!(execute(&instructions, [0, 0]), [1, 0]);
+
+        for (line, column) in [("x", None), ("inc c", Some(5)), ("jie b, x", Some(8))] {
+            let error = Day::parse(line).unwrap_err();
+            let error = error.downcast::<ParseError>().unwrap();
+            assert_eq!((error.line, error.column), (Some(1), column), "{}", line);
+        }
+    }
+}
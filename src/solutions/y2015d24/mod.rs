@@ -1,37 +1,37 @@
 use std::cmp::Ordering;
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 
-fn main() -> Result<()> {
-    let path = env::current_dir()?.join("src/bin/y2015d24/input.txt");
-    let reader = BufReader::new(File::open(path)?);
+use crate::parse;
+use crate::solution::{Answer, Solution};
 
-    let weights: Result<Vec<i32>> = reader
-        .lines()
-        .map(|l| {
-            l.map_err(Into::into)
-                .and_then(|s| s.parse().map_err(Into::into))
-        })
-        .collect();
+pub struct Day;
 
-    let weights = weights?;
+impl Solution for Day {
+    /// Weights of the packages.
+    type Input = Vec<i32>;
 
-    let total: i32 = weights.iter().sum();
-    assert_eq!(total % 3, 0);
-    assert_eq!(total % 4, 0);
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, |l| Ok(l.parse()?))
+    }
 
-    let target_weight = total / 4;
+    fn part2(weights: &Self::Input) -> Result<Answer> {
+        Ok(Some(quantum_entanglement(weights, 4)?.to_string()))
+    }
+}
 
-    let res = find_best_subset(&weights, target_weight).unwrap();
-    let prod: i64 = res.iter().map(|&i| i as i64).product();
+/// Product of the smallest group in the best split of the packages into `groups` equal groups.
+fn quantum_entanglement(weights: &[i32], groups: i32) -> Result<i64> {
+    let total: i32 = weights.iter().sum();
+    if total % groups != 0 {
+        bail!("Packages can not be split into {} groups", groups);
+    }
 
-    println!("{prod}");
+    let target_weight = total / groups;
 
-    Ok(())
+    let res = find_best_subset(weights, target_weight)
+        .ok_or(anyhow!("No group weighs {}", target_weight))?;
+    Ok(res.iter().map(|&i| i as i64).product())
 }
 
 fn find_best_subset(candidates: &[i32], target_weight: i32) -> Option<Vec<i32>> {
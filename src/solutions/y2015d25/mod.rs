@@ -0,0 +1,41 @@
+use anyhow::{bail, Result};
+
+use crate::math::{modpow, mulmod};
+use crate::parse::regex_struct;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Target;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let target: Target = input.trim().parse()?;
+        if target.row == 0 || target.col == 0 {
+            bail!("Rows and columns start at 1");
+        }
+
+        Ok(target)
+    }
+
+    fn part1(target: &Self::Input) -> Result<Answer> {
+        Ok(Some(code(target).to_string()))
+    }
+}
+
+fn code(target: &Target) -> u64 {
+    // Codes are filled in diagonally, so count the codes on the preceding diagonals first.
+    let diagonal = (target.row + target.col - 1) as u64;
+    let index = diagonal * (diagonal - 1) / 2 + target.col as u64 - 1;
+
+    mulmod(20151125, modpow(252533, index, 33554393), 33554393)
+}
+
+regex_struct! {
+    #[regex = r"Enter the code at row (?<row>\d+), column (?<col>\d+)\.$"]
+    #[derive(Debug)]
+    pub struct Target {
+        row: usize,
+        col: usize,
+    }
+}
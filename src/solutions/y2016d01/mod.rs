@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+type Location = (i32, i32);
+
+pub struct Day;
+
+impl Solution for Day {
+    /// Every location visited on the way, starting at the origin.
+    type Input = Vec<Location>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut location = (0i32, 0i32);
+        let mut heading = (1, 0);
+        let mut path = vec![location];
+
+        let line = input.trim();
+        let mut column = 1;
+        for instruction in line.split(", ") {
+            let at_instruction = |e| parse::in_line(e, 1, line).with_column(column);
+
+            if !instruction.is_char_boundary(1) {
+                return Err(at_instruction(anyhow!("Invalid instruction {}", instruction)).into());
+            }
+            let (direction, steps) = instruction.split_at(1);
+            let steps = steps.parse::<i32>().map_err(|e| at_instruction(e.into()))?;
+
+            match direction {
+                "L" => {
+                    (heading.0, heading.1) = (-heading.1, heading.0);
+                }
+                "R" => {
+                    (heading.0, heading.1) = (heading.1, -heading.0);
+                }
+                _ => {
+                    let e = anyhow!("Invalid direction {}", direction);
+                    return Err(at_instruction(e).into());
+                }
+            }
+            column += instruction.chars().count() + 2;
+
+            for _ in 0..steps {
+                location.0 += heading.0;
+                location.1 += heading.1;
+                path.push(location);
+            }
+        }
+
+        Ok(path)
+    }
+
+    fn part1(path: &Self::Input) -> Result<Answer> {
+        Ok(path.last().map(|&l| distance(l).to_string()))
+    }
+
+    fn part2(path: &Self::Input) -> Result<Answer> {
+        let mut visited = std::collections::HashSet::new();
+        let first_repeated = path.iter().find(|&&l| !visited.insert(l));
+
+        Ok(first_repeated.map(|&l| distance(l).to_string()))
+    }
+}
+
+fn distance(location: Location) -> i32 {
+    location.0.abs() + location.1.abs()
+}
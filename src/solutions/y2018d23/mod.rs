@@ -1,44 +1,62 @@
 use std::cmp::Ordering;
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
 use anyhow::Result;
 use itertools::{iproduct, Itertools};
 
-type Coord = (i32, i32, i32);
-
-fn main() -> Result<()> {
-    let path = env::current_dir()?.join("src/bin/y2018d23/input.txt");
-    let reader = BufReader::new(File::open(path)?);
-
-    let re = regex::Regex::new(r"^pos=<(-?\d+),(-?\d+),(-?\d+)>, r=(\d+)$")?;
-    let mut bots = Vec::new();
+use crate::log;
+use crate::parse;
+use crate::solution::{Answer, Solution};
 
-    for line in reader.lines() {
-        let line = line?;
-        let caps = re
-            .captures(&line)
-            .ok_or(anyhow::anyhow!("Invalid input: {}", line))?;
-        let x = caps[1].parse::<i32>()?;
-        let y = caps[2].parse::<i32>()?;
-        let z = caps[3].parse::<i32>()?;
-        let r = caps[4].parse::<i32>()?;
+type Coord = (i32, i32, i32);
+/// Position and radius of a nanobot.
+type Bot = (i32, i32, i32, i32);
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Bot>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = regex::Regex::new(r"^pos=<(-?\d+),(-?\d+),(-?\d+)>, r=(\d+)$")?;
+        let bots = parse::lines(input, |line| {
+            let caps = re
+                .captures(line)
+                .ok_or(anyhow::anyhow!("Invalid input: {}", line))?;
+            let x = caps[1].parse::<i32>()?;
+            let y = caps[2].parse::<i32>()?;
+            let z = caps[3].parse::<i32>()?;
+            let r = caps[4].parse::<i32>()?;
+
+            Ok((x, y, z, r))
+        })?;
+
+        if bots.is_empty() {
+            anyhow::bail!("No nanobots");
+        }
 
-        bots.push((x, y, z, r));
+        Ok(bots)
     }
 
-    let max_bot = bots.iter().max_by_key(|b| b.3).unwrap();
-    let in_range = bots
-        .iter()
-        .filter(|b| {
-            (max_bot.0 - b.0).abs() + (max_bot.1 - b.1).abs() + (max_bot.2 - b.2).abs() <= max_bot.3
-        })
-        .count();
+    fn part1(bots: &Self::Input) -> Result<Answer> {
+        let max_bot = bots.iter().max_by_key(|b| b.3).unwrap();
+        let in_range = bots
+            .iter()
+            .filter(|b| {
+                (max_bot.0 - b.0).abs() + (max_bot.1 - b.1).abs() + (max_bot.2 - b.2).abs()
+                    <= max_bot.3
+            })
+            .count();
+
+        Ok(Some(in_range.to_string()))
+    }
 
-    println!("Part 1: {}", in_range);
+    fn part2(bots: &Self::Input) -> Result<Answer> {
+        Ok(Some(manhattan_distance(best_location(bots)).to_string()))
+    }
+}
 
+/// Location in range of the most nanobots, closest to the origin.
+fn best_location(bots: &[Bot]) -> Coord {
     // Determine max area
     // Split into 8 sub-cubes
     // Calc max intersections for sub-cubes
@@ -56,14 +74,14 @@ fn main() -> Result<()> {
         .unwrap()
         .next_power_of_two() as i32;
 
-    println!(
+    log::debug!(
         "Max cube side: {}, (2**{})",
         max_cube_side,
         max_cube_side.ilog2()
     );
 
     let (loc, intersections) = search(
-        &bots,
+        bots,
         (-max_cube_side, -max_cube_side, -max_cube_side),
         2 * max_cube_side,
         0,
@@ -71,14 +89,13 @@ fn main() -> Result<()> {
 
     let loc = loc.unwrap();
 
-    println!("{:?} {}", loc, intersections);
-    println!("Part 2: {:?}", manhattan_distance(loc));
+    log::debug!("{:?} {}", loc, intersections);
 
-    Ok(())
+    loc
 }
 
 fn search(
-    bots: &[(i32, i32, i32, i32)],
+    bots: &[Bot],
     cube: Coord,
     cube_side: i32,
     mut most_intersection_seen: usize,
@@ -130,7 +147,7 @@ fn search(
 /// Count the number of intersections in the cube defined by the given parameters:
 /// - x, y, z: The coordinates of the cube's bottom-left corner
 /// - cube_side: The length of the cube's side
-fn count_intersections(bots: &[(i32, i32, i32, i32)], cube: Coord, cube_side: i32) -> usize {
+fn count_intersections(bots: &[Bot], cube: Coord, cube_side: i32) -> usize {
     debug_assert!(cube_side > 0);
 
     let (x, y, z) = cube;
@@ -152,6 +169,3 @@ fn count_intersections(bots: &[(i32, i32, i32, i32)], cube: Coord, cube_side: i3
 fn manhattan_distance(c: Coord) -> i32 {
     c.0.abs() + c.1.abs() + c.2.abs()
 }
-
-#[cfg(test)]
-mod tests {}
@@ -0,0 +1,419 @@
+use anyhow::{anyhow, bail, Result};
+use std::collections::{HashMap, VecDeque};
+
+use crate::grid::{Grid, Pos};
+use crate::search::{bfs, dijkstra};
+use crate::solution::{Answer, Part, Solution};
+
+type Maze = Grid<Tile>;
+type MazeIndex = HashMap<Pos, Vec<(Pos, u32)>>;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vault;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // Keys and doors are numbered in the order they appear, a key and its door share the
+        // number of the lowercase letter.
+        let mut ids: HashMap<char, u8> = HashMap::new();
+        let mut id = |c: char| {
+            let letter = c.to_lowercase().next().unwrap_or(c);
+            let next = ids.len();
+            if next >= KeySet::CAPACITY && !ids.contains_key(&letter) {
+                bail!("More than {} keys", KeySet::CAPACITY);
+            }
+            Ok(*ids.entry(letter).or_insert(next as u8))
+        };
+
+        let maze = Grid::parse(input, |c| match c {
+            '@' => Ok(Tile::Start),
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Floor),
+            c if c.is_lowercase() => Ok(Tile::Key(id(c)?)),
+            c if c.is_uppercase() => Ok(Tile::Door(id(c)?)),
+            _ => Err(anyhow!("Invalid tile {}", c)),
+        })?;
+
+        let starts: Vec<Pos> = maze.find_all(|&t| t == Tile::Start).collect();
+        if starts.is_empty() {
+            bail!("Missing start");
+        }
+
+        let key_count = maze.find_all(|t| matches!(t, Tile::Key(_))).count();
+
+        Ok(Vault {
+            maze,
+            starts,
+            key_count,
+        })
+    }
+
+    fn part1(vault: &Self::Input) -> Result<Answer> {
+        let steps = collect_keys(vault, false)?;
+        Ok(Some(steps.to_string()))
+    }
+
+    fn part2(vault: &Self::Input) -> Result<Answer> {
+        // Vaults that are split up already are searched as they are.
+        let steps = collect_keys(vault, vault.starts.len() == 1)?;
+        Ok(Some(steps.to_string()))
+    }
+
+    /// The search before the key graph, to see its speedup with `aoc bench`.
+    fn alternatives() -> Vec<(&'static str, Part<Self::Input>)> {
+        vec![
+            ("part1-old", |vault| {
+                let steps = collect_keys_through_maze(vault, false)?;
+                Ok(Some(steps.to_string()))
+            }),
+            ("part2-old", |vault| {
+                let steps = collect_keys_through_maze(vault, vault.starts.len() == 1)?;
+                Ok(Some(steps.to_string()))
+            }),
+        ]
+    }
+}
+
+#[derive(Debug)]
+pub struct Vault {
+    maze: Maze,
+    starts: Vec<Pos>,
+    key_count: usize,
+}
+
+/// Set of keys by number, for up to [`KeySet::CAPACITY`] keys.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+struct KeySet(u128);
+
+impl KeySet {
+    const CAPACITY: usize = 128;
+
+    fn contains(self, key: u8) -> bool {
+        self.0 & (1 << key) != 0
+    }
+
+    fn with(self, key: u8) -> Self {
+        KeySet(self.0 | (1 << key))
+    }
+
+    fn contains_all(self, other: KeySet) -> bool {
+        other.0 & !self.0 == 0
+    }
+
+    fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+}
+
+/// Fewest steps for the robots, one at every start, to collect all keys. With `split_centre`
+/// the single start is walled in first, with a robot on each of its diagonals instead.
+fn collect_keys(vault: &Vault, split_centre: bool) -> Result<u32> {
+    let (maze, robots) = if split_centre {
+        split(&vault.maze, &vault.starts)?
+    } else {
+        (vault.maze.clone(), vault.starts.clone())
+    };
+    let graph = KeyGraph::new(&maze, &robots);
+
+    // The robots are at the nodes of the graph, starting at the first ones.
+    let search = dijkstra(
+        ((0..robots.len()).collect(), KeySet::default()),
+        |(robots, keys): &(Vec<usize>, KeySet)| {
+            let mut next = Vec::new();
+            for (i, &node) in robots.iter().enumerate() {
+                for route in graph.routes(node, *keys) {
+                    let mut robots = robots.clone();
+                    robots[i] = route.to;
+                    next.push(((robots, keys.with(route.key)), route.steps));
+                }
+            }
+            next
+        },
+        |(_, keys)| keys.len() == vault.key_count,
+    );
+
+    search
+        .goal_cost()
+        .ok_or(anyhow!("Not all keys can be collected"))
+}
+
+/// Like [`collect_keys`], with a search through the maze from every state instead of the key
+/// graph.
+fn collect_keys_through_maze(vault: &Vault, split_centre: bool) -> Result<u32> {
+    let (maze, robots) = if split_centre {
+        split(&vault.maze, &vault.starts)?
+    } else {
+        (vault.maze.clone(), vault.starts.clone())
+    };
+    let index = index_maze(&maze);
+
+    let search = dijkstra(
+        (robots, KeySet::default()),
+        |(robots, keys): &(Vec<Pos>, KeySet)| {
+            let mut next = Vec::new();
+            for (i, &pos) in robots.iter().enumerate() {
+                for (steps, pos, keys) in reachable_keys(&maze, &index, pos, *keys) {
+                    let mut robots = robots.clone();
+                    robots[i] = pos;
+                    next.push(((robots, keys), steps));
+                }
+            }
+            next
+        },
+        |(_, keys)| keys.len() == vault.key_count,
+    );
+
+    search
+        .goal_cost()
+        .ok_or(anyhow!("Not all keys can be collected"))
+}
+
+/// Replace the 3x3 area around the single start with walls in a plus shape and four starts in
+/// the corners.
+fn split(maze: &Maze, starts: &[Pos]) -> Result<(Maze, Vec<Pos>)> {
+    let &[(row, col)] = starts else {
+        bail!("Only a vault with a single start can be split");
+    };
+
+    let mut maze = maze.clone();
+    let mut robots = Vec::new();
+    for drow in -1..=1 {
+        for dcol in -1..=1 {
+            let pos = maze
+                .checked_pos(row as i64 + drow, col as i64 + dcol)
+                .ok_or(anyhow!("The start is at the edge of the vault"))?;
+
+            if drow != 0 && dcol != 0 {
+                maze[pos] = Tile::Start;
+                robots.push(pos);
+            } else {
+                maze[pos] = Tile::Wall;
+            }
+        }
+    }
+
+    Ok((maze, robots))
+}
+
+/// Shortest route from a start or key to a key, ignoring the doors, with what it passes.
+#[derive(Debug, Copy, Clone)]
+struct Route {
+    /// Node of the key at the end.
+    to: usize,
+    key: u8,
+    steps: u32,
+    /// Doors on the way, which need their keys.
+    doors: KeySet,
+    /// Keys on the way, excluding the one at the end.
+    keys: KeySet,
+}
+
+/// Routes between all starts and keys, the nodes. Precomputing them turns the search for the
+/// keys into a search over sets of keys, where every step is a single route.
+#[derive(Debug)]
+struct KeyGraph {
+    routes: Vec<Vec<Route>>,
+}
+
+impl KeyGraph {
+    /// The graph with the starts as the first nodes, in the same order.
+    fn new(maze: &Maze, starts: &[Pos]) -> Self {
+        let keys = maze.find_all(|t| matches!(t, Tile::Key(_)));
+        let nodes: Vec<Pos> = starts.iter().copied().chain(keys).collect();
+        let node_of: HashMap<Pos, usize> = nodes.iter().enumerate().map(|(i, &p)| (p, i)).collect();
+
+        let routes = nodes
+            .iter()
+            .map(|&from| routes_from(maze, from, &node_of))
+            .collect();
+
+        KeyGraph { routes }
+    }
+
+    /// Routes from `node` to the keys that are not collected yet, through opened doors only.
+    /// Routes past other new keys are left out, as they are covered by the route to the first one.
+    fn routes(&self, node: usize, keys: KeySet) -> impl Iterator<Item = &Route> {
+        self.routes[node].iter().filter(move |route| {
+            !keys.contains(route.key)
+                && keys.contains_all(route.doors)
+                && keys.contains_all(route.keys)
+        })
+    }
+}
+
+/// Breadth-first search from `from` to every key, keeping track of what each route passes.
+fn routes_from(maze: &Maze, from: Pos, node_of: &HashMap<Pos, usize>) -> Vec<Route> {
+    let mut routes = Vec::new();
+    let mut seen = Grid::new(maze.width(), maze.height(), false);
+    seen[from] = true;
+    let mut queue = VecDeque::from([(from, 0, KeySet::default(), KeySet::default())]);
+
+    while let Some((pos, steps, mut doors, mut keys)) = queue.pop_front() {
+        match maze[pos] {
+            Tile::Key(key) if pos != from => {
+                routes.push(Route {
+                    to: node_of[&pos],
+                    key,
+                    steps,
+                    doors,
+                    keys,
+                });
+                keys = keys.with(key);
+            }
+            Tile::Door(door) => doors = doors.with(door),
+            _ => {}
+        }
+
+        for next in maze.neighbours4(pos) {
+            if maze[next] != Tile::Wall && !seen[next] {
+                seen[next] = true;
+                queue.push_back((next, steps + 1, doors, keys));
+            }
+        }
+    }
+
+    routes
+}
+
+/// For the search without the key graph: for each key, door and start location find the shortest paths to all other keys and doors,
+/// that are directly reachable from that location.
+fn index_maze(maze: &Maze) -> MazeIndex {
+    let mut index = HashMap::new();
+
+    for pos in maze.find_all(|t| matches!(t, Tile::Key(_) | Tile::Door(_) | Tile::Start)) {
+        index.insert(pos, directly_reachable(maze, pos));
+    }
+
+    index
+}
+
+fn directly_reachable(maze: &Maze, start: Pos) -> Vec<(Pos, u32)> {
+    let is_stop = |pos: &Pos| *pos != start && matches!(maze[*pos], Tile::Key(_) | Tile::Door(_));
+
+    let search = bfs(
+        start,
+        |pos| {
+            let neighbours: Vec<Pos> = if is_stop(pos) {
+                Vec::new()
+            } else {
+                maze.neighbours4(*pos)
+                    .filter(|&next| maze[next] != Tile::Wall)
+                    .collect()
+            };
+            neighbours
+        },
+        |_| false,
+    );
+
+    search
+        .distances()
+        .iter()
+        .filter(|(pos, _)| is_stop(pos))
+        .map(|(&pos, &steps)| (pos, steps as u32))
+        .collect()
+}
+
+/// Keys that are not collected yet and can be reached without passing through another one,
+/// as `(steps, position, keys after picking it up)`.
+fn reachable_keys(
+    maze: &Maze,
+    index: &MazeIndex,
+    pos: Pos,
+    keys: KeySet,
+) -> Vec<(u32, Pos, KeySet)> {
+    let is_new_key = |pos: &Pos| matches!(maze[*pos], Tile::Key(k) if !keys.contains(k));
+
+    let search = dijkstra(
+        pos,
+        |pos| {
+            // Walking onto a new key picks it up, so the search doesn't continue past it.
+            let edges = if is_new_key(pos) {
+                &[][..]
+            } else {
+                &index[pos][..]
+            };
+
+            edges.iter().copied().filter(|(next, _)| match maze[*next] {
+                Tile::Door(d) => keys.contains(d),
+                _ => true,
+            })
+        },
+        |_| false,
+    );
+
+    search
+        .distances()
+        .iter()
+        .filter_map(|(&pos, &steps)| match maze[pos] {
+            Tile::Key(k) if !keys.contains(k) => Some((steps, pos, keys.with(k))),
+            _ => None,
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Tile {
+    Start,
+    Wall,
+    Floor,
+    Key(u8),
+    Door(u8),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_graph() {
+        let vault = Day::parse("#########\n#b.A.@.a#\n#########\n").unwrap();
+        let graph = KeyGraph::new(&vault.maze, &vault.starts);
+
+        // Keys are numbered in order of appearance, b is 0, A and a are 1.
+        let (b, a) = (0, 1);
+        let mut routes = graph.routes[0].clone();
+        routes.sort_by_key(|r| r.steps);
+        assert_eq!((routes[0].key, routes[0].steps), (a, 2));
+        assert_eq!((routes[1].key, routes[1].steps), (b, 4));
+        assert_eq!(routes[1].doors, KeySet::default().with(a));
+
+        assert_eq!(graph.routes(0, KeySet::default()).count(), 1);
+        assert_eq!(graph.routes(0, KeySet::default().with(a)).count(), 1);
+        assert_eq!(Day::part1(&vault).unwrap(), Some("8".into()));
+    }
+
+    #[test]
+    fn test_multiple_starts() {
+        let vault =
+            Day::parse("#######\n#a.#Cd#\n##@#@##\n#######\n##@#@##\n#cB#Ab#\n#######\n").unwrap();
+        assert_eq!(vault.starts.len(), 4);
+        assert_eq!(Day::part1(&vault).unwrap(), Some("8".into()));
+        assert_eq!(Day::part2(&vault).unwrap(), Some("8".into()));
+        for (_, part) in Day::alternatives() {
+            assert_eq!(part(&vault).unwrap(), Some("8".into()));
+        }
+        assert!(collect_keys(&vault, true).is_err());
+    }
+
+    #[test]
+    fn test_many_keys() {
+        // 39 keys, the Greek ones behind a door that needs the key left of the start.
+        let row = "#ξ@abcdefghijklmnopqrstuvwxyzΞαβγδεζηθικλμ#";
+        let wall = "#".repeat(row.chars().count());
+        let vault = Day::parse(&format!("{}\n{}\n{}\n", wall, row, wall)).unwrap();
+
+        assert_eq!(vault.key_count, 39);
+        assert_eq!(Day::part1(&vault).unwrap(), Some("41".into()));
+    }
+
+    #[test]
+    fn test_unreachable_keys() {
+        // The only key is behind its own door.
+        let vault = Day::parse("######\n#@.Aa#\n######\n").unwrap();
+        assert!(Day::part1(&vault).is_err());
+        for (_, part) in Day::alternatives() {
+            assert!(part(&vault).is_err());
+        }
+    }
+}
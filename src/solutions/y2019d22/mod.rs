@@ -0,0 +1,576 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::math::{gcd, mod_inverse, mulmod};
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Shuffle>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, str::parse)
+    }
+
+    fn part1(shuffles: &Self::Input) -> Result<Answer> {
+        let deck_size = 10007;
+        let mut position = 2019;
+        for shuffle in shuffles {
+            position = shuffle.track_position(deck_size, position);
+        }
+
+        Ok(Some(position.to_string()))
+    }
+
+    fn part2(shuffles: &Self::Input) -> Result<Answer> {
+        let deck_size = 119315717514047;
+        let repetitions = 101741582076661;
+
+        let shuffle = Affine::compose(shuffles, deck_size).pow(repetitions);
+        let inverse = shuffle
+            .inverse()
+            .ok_or(anyhow!("The shuffle can not be undone"))?;
+
+        Ok(Some(inverse.apply(2020).to_string()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shuffle {
+    DealIntoNewStack,
+    Cut(isize),
+    DealWithIncrement(usize),
+}
+
+impl FromStr for Shuffle {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "deal into new stack" {
+            Ok(Shuffle::DealIntoNewStack)
+        } else if let Some(n) = s.strip_prefix("cut ") {
+            Ok(Shuffle::Cut(argument(s, n)?))
+        } else if let Some(n) = s.strip_prefix("deal with increment ") {
+            Ok(Shuffle::DealWithIncrement(argument(s, n)?))
+        } else {
+            bail!("Unknown shuffle: {}", s);
+        }
+    }
+}
+
+/// Parse the number at the end of `line`, pointing errors at it.
+fn argument<T>(line: &str, n: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    n.parse().map_err(|e| {
+        let column = line[..line.len() - n.len()].chars().count() + 1;
+        ParseError::new(format!("Invalid number {:?}: {}", n, e))
+            .with_column(column)
+            .into()
+    })
+}
+
+/// The text syntax of the puzzle input.
+impl fmt::Display for Shuffle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shuffle::DealIntoNewStack => write!(f, "deal into new stack"),
+            Shuffle::Cut(n) => write!(f, "cut {}", n),
+            Shuffle::DealWithIncrement(n) => write!(f, "deal with increment {}", n),
+        }
+    }
+}
+
+/// Reduce a sequence of shuffles to an equivalent one for decks of the given size, with at most
+/// a `DealIntoNewStack`, a `DealWithIncrement` and a `Cut`, in that order. Fails if the sequence
+/// deals several cards onto the same position.
+pub fn simplify(shuffles: &[Shuffle], deck_size: usize) -> Result<Vec<Shuffle>> {
+    if deck_size == 0 {
+        bail!("The deck needs at least one card");
+    }
+
+    let n = deck_size as u64;
+    let Affine { a, b, .. } = Affine::compose(shuffles, n);
+    if gcd(a as i64, n as i64) != 1 {
+        bail!("The shuffles can't be simplified, they lose cards");
+    }
+
+    // Reversing first keeps the increment below half the deck, x -> -x - 1 -> -kx - k -> -kx - k - c
+    let reverse = a > n / 2;
+    let increment = if reverse { n - a } else { a };
+    let offset = if reverse { (increment + b) % n } else { b };
+    // The cut removes the offset, take the shorter of the two equivalent ones.
+    let cut = (n - offset) % n;
+    let cut = if cut > n / 2 {
+        cut as isize - n as isize
+    } else {
+        cut as isize
+    };
+
+    let mut simplified = Vec::new();
+    if reverse {
+        simplified.push(Shuffle::DealIntoNewStack);
+    }
+    if increment != 1 % n {
+        simplified.push(Shuffle::DealWithIncrement(increment as usize));
+    }
+    if cut != 0 {
+        simplified.push(Shuffle::Cut(cut));
+    }
+
+    Ok(simplified)
+}
+
+impl Shuffle {
+    /// The shuffle that undoes this one, `None` if the increment and the deck size are not
+    /// coprime, as several cards are dealt onto the same position then.
+    pub fn invert(&self, deck_size: usize) -> Option<Self> {
+        let inverted = match self {
+            Shuffle::DealIntoNewStack => Shuffle::DealIntoNewStack,
+            Shuffle::Cut(n) => Shuffle::Cut(-n),
+            Shuffle::DealWithIncrement(n) => {
+                let inverse = mod_inverse(n.checked_rem(deck_size)? as i64, deck_size as i64)?;
+                Shuffle::DealWithIncrement(inverse as usize)
+            }
+        };
+        Some(inverted)
+    }
+
+    /// New position of the card at `position`, panics if it is not in the deck.
+    pub fn track_position(&self, deck_size: usize, position: usize) -> usize {
+        assert!(
+            position < deck_size,
+            "Position {} is out of bounds",
+            position
+        );
+
+        match self {
+            Shuffle::DealIntoNewStack => deck_size - position - 1,
+            Shuffle::Cut(n) => {
+                (position as i128 - *n as i128).rem_euclid(deck_size as i128) as usize
+            }
+            Shuffle::DealWithIncrement(n) => {
+                mulmod(position as u64, *n as u64, deck_size as u64) as usize
+            }
+        }
+    }
+
+    /// The position tracking as an affine map.
+    fn affine(&self, deck_size: u64) -> Affine {
+        let (a, b) = match self {
+            Shuffle::DealIntoNewStack => (deck_size - 1, deck_size - 1),
+            Shuffle::Cut(n) => (1, (-(*n as i128)).rem_euclid(deck_size as i128) as u64),
+            Shuffle::DealWithIncrement(n) => (*n as u64 % deck_size, 0),
+        };
+        Affine { a, b, deck_size }
+    }
+}
+
+/// An explicit deck of cards, with card `i` at position `i` before shuffling. Fine for decks of
+/// up to a few million cards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    pub cards: Vec<u32>,
+}
+
+impl Deck {
+    pub fn new(size: usize) -> Self {
+        Deck {
+            cards: (0..size as u32).collect(),
+        }
+    }
+
+    pub fn shuffle(&mut self, shuffle: &Shuffle) -> Result<()> {
+        let size = self.cards.len();
+        if size == 0 {
+            return Ok(());
+        }
+
+        match shuffle {
+            Shuffle::DealIntoNewStack => self.cards.reverse(),
+            Shuffle::Cut(n) => {
+                let n = (*n as i128).rem_euclid(size as i128) as usize;
+                self.cards.rotate_left(n);
+            }
+            Shuffle::DealWithIncrement(n) => {
+                if gcd((*n % size) as i64, size as i64) != 1 {
+                    bail!(
+                        "Dealing with increment {} puts several cards on the same position of a {} card deck",
+                        n,
+                        size
+                    );
+                }
+
+                let mut dealt = vec![0; size];
+                for (i, &card) in self.cards.iter().enumerate() {
+                    dealt[mulmod(i as u64, *n as u64, size as u64) as usize] = card;
+                }
+                self.cards = dealt;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn position(&self, card: u32) -> Option<usize> {
+        self.cards.iter().position(|&c| c == card)
+    }
+}
+
+/// Maps a position to `(a * position + b) % deck_size`. Every shuffle is such a map, and so is
+/// any sequence of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Affine {
+    a: u64,
+    b: u64,
+    deck_size: u64,
+}
+
+impl Affine {
+    fn identity(deck_size: u64) -> Self {
+        Affine {
+            a: 1 % deck_size,
+            b: 0,
+            deck_size,
+        }
+    }
+
+    /// The whole sequence of shuffles as a single map.
+    fn compose(shuffles: &[Shuffle], deck_size: u64) -> Self {
+        shuffles
+            .iter()
+            .fold(Affine::identity(deck_size), |affine, shuffle| {
+                affine.then(&shuffle.affine(deck_size))
+            })
+    }
+
+    fn apply(&self, position: u64) -> u64 {
+        (mulmod(self.a, position, self.deck_size) + self.b) % self.deck_size
+    }
+
+    /// First `self`, then `next`.
+    fn then(&self, next: &Affine) -> Self {
+        let n = self.deck_size;
+        Affine {
+            a: mulmod(next.a, self.a, n),
+            b: (mulmod(next.a, self.b, n) + next.b) % n,
+            deck_size: n,
+        }
+    }
+
+    /// `self` repeated `times` times, by repeated squaring.
+    fn pow(&self, times: u64) -> Self {
+        let mut result = Affine::identity(self.deck_size);
+        let mut square = *self;
+        let mut times = times;
+
+        while times > 0 {
+            if times & 1 == 1 {
+                result = result.then(&square);
+            }
+            square = square.then(&square);
+            times >>= 1;
+        }
+
+        result
+    }
+
+    /// Maps the positions back, `None` if several positions map to the same one.
+    fn inverse(&self) -> Option<Self> {
+        let n = self.deck_size;
+        // position = a^-1 * (new - b)
+        let a = mod_inverse(self.a as i64, n as i64)? as u64;
+        Some(Affine {
+            a,
+            b: mulmod(a, n - self.b, n),
+            deck_size: n,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_deal_into_new_stack() {
+        let deck_size = 10;
+        let shuffle = Shuffle::DealIntoNewStack;
+        let inverted = shuffle.invert(deck_size).unwrap();
+
+        assert_eq!(shuffle.track_position(deck_size, 0), 9);
+        assert_eq!(shuffle.track_position(deck_size, 1), 8);
+
+        for i in 0..deck_size {
+            let new_position = shuffle.track_position(deck_size, i);
+            assert_eq!(inverted.track_position(deck_size, new_position), i);
+        }
+    }
+
+    #[test]
+    fn test_cut_positive() {
+        let deck_size = 10;
+        let shuffle = Shuffle::Cut(3);
+        let inverted = shuffle.invert(deck_size).unwrap();
+
+        assert_eq!(shuffle.track_position(deck_size, 0), 7);
+        assert_eq!(shuffle.track_position(deck_size, 2), 9);
+        assert_eq!(shuffle.track_position(deck_size, 3), 0);
+        assert_eq!(shuffle.track_position(deck_size, 9), 6);
+
+        for i in 0..deck_size {
+            let new_position = shuffle.track_position(deck_size, i);
+            assert_eq!(
+                inverted.track_position(deck_size, new_position),
+                i,
+                "Failed for {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_cut_negative() {
+        let deck_size = 10;
+        let shuffle = Shuffle::Cut(-4);
+        let inverted = shuffle.invert(deck_size).unwrap();
+
+        assert_eq!(shuffle.track_position(deck_size, 0), 4);
+        assert_eq!(shuffle.track_position(deck_size, 2), 6);
+        assert_eq!(shuffle.track_position(deck_size, 3), 7);
+        assert_eq!(shuffle.track_position(deck_size, 9), 3);
+
+        for i in 0..deck_size {
+            let new_position = shuffle.track_position(deck_size, i);
+            assert_eq!(
+                inverted.track_position(deck_size, new_position),
+                i,
+                "Failed for {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_deal_with_increment() {
+        let deck_size = 10;
+        let shuffle = Shuffle::DealWithIncrement(3);
+        let inverted = shuffle.invert(deck_size).unwrap();
+
+        assert_eq!(shuffle.track_position(deck_size, 0), 0);
+        assert_eq!(shuffle.track_position(deck_size, 1), 3);
+        assert_eq!(shuffle.track_position(deck_size, 2), 6);
+        assert_eq!(shuffle.track_position(deck_size, 3), 9);
+        assert_eq!(shuffle.track_position(deck_size, 4), 2);
+        assert_eq!(shuffle.track_position(deck_size, 5), 5);
+
+        for i in 0..deck_size {
+            let new_position = shuffle.track_position(deck_size, i);
+            assert_eq!(
+                inverted.track_position(deck_size, new_position),
+                i,
+                "Failed for {}",
+                i
+            );
+        }
+    }
+
+    #[test]
+    fn test_affine() {
+        let shuffles = Day::parse(
+            "deal into new stack\ncut -2\ndeal with increment 7\ncut 8\ncut -4\n\
+             deal with increment 7\ncut 3\ndeal with increment 9\ndeal with increment 3\ncut -1\n",
+        )
+        .unwrap();
+        let deck_size = 10;
+        let affine = Affine::compose(&shuffles, deck_size as u64);
+
+        let track = |position| {
+            shuffles
+                .iter()
+                .fold(position, |p, s| s.track_position(deck_size, p))
+        };
+        for position in 0..deck_size {
+            assert_eq!(affine.apply(position as u64), track(position) as u64);
+        }
+
+        // Result: 9 2 5 8 1 4 7 0 3 6, card 9 ends up on top.
+        let inverse = affine.inverse().unwrap();
+        let deck: Vec<u64> = (0..10).map(|p| inverse.apply(p)).collect();
+        assert_eq!(deck, [9, 2, 5, 8, 1, 4, 7, 0, 3, 6]);
+    }
+
+    #[test]
+    fn test_affine_pow() {
+        let deck_size = 10007;
+        let shuffles = [Shuffle::Cut(-1234), Shuffle::DealWithIncrement(17)];
+        let affine = Affine::compose(&shuffles, deck_size as u64);
+
+        let mut position = 2019;
+        for _ in 0..1000 {
+            for shuffle in &shuffles {
+                position = shuffle.track_position(deck_size, position);
+            }
+        }
+        let repeated = affine.pow(1000);
+        assert_eq!(repeated.apply(2019), position as u64);
+        assert_eq!(repeated.inverse().unwrap().apply(position as u64), 2019);
+
+        assert_eq!(affine.pow(0), Affine::identity(deck_size as u64));
+        let not_invertible = Affine::compose(&[Shuffle::DealWithIncrement(2)], 10);
+        assert_eq!(not_invertible.inverse(), None);
+    }
+
+    #[test]
+    fn test_deck() {
+        let mut deck = Deck::new(10);
+        for shuffle in [
+            Shuffle::DealWithIncrement(7),
+            Shuffle::DealIntoNewStack,
+            Shuffle::DealIntoNewStack,
+        ] {
+            deck.shuffle(&shuffle).unwrap();
+        }
+        assert_eq!(deck.cards, [0, 3, 6, 9, 2, 5, 8, 1, 4, 7]);
+
+        deck.shuffle(&Shuffle::Cut(-4)).unwrap();
+        assert_eq!(deck.cards, [8, 1, 4, 7, 0, 3, 6, 9, 2, 5]);
+        assert_eq!(deck.position(3), Some(5));
+
+        assert!(deck.shuffle(&Shuffle::DealWithIncrement(4)).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "deal into new stack\ncut -2\ndeal with increment 7\n";
+        let shuffles = Day::parse(input).unwrap();
+        assert_eq!(
+            shuffles,
+            [
+                Shuffle::DealIntoNewStack,
+                Shuffle::Cut(-2),
+                Shuffle::DealWithIncrement(7)
+            ]
+        );
+        let text: String = shuffles.iter().map(|s| format!("{}\n", s)).collect();
+        assert_eq!(text, input);
+
+        let error = Day::parse("cut 3\ndeal with increment -7\n").unwrap_err();
+        let error = error.downcast::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (Some(2), Some(21)));
+        assert!("deal into new stacks".parse::<Shuffle>().is_err());
+    }
+
+    #[test]
+    fn test_simplify() {
+        let shuffles = Day::parse(
+            "deal into new stack\ncut -2\ndeal with increment 7\ncut 8\ncut -4\n\
+             deal with increment 7\ncut 3\ndeal with increment 9\ndeal with increment 3\ncut -1\n",
+        )
+        .unwrap();
+
+        let simplified = simplify(&shuffles, 10).unwrap();
+        assert_eq!(
+            simplified,
+            [Shuffle::DealIntoNewStack, Shuffle::DealWithIncrement(3)]
+        );
+        assert_eq!(
+            Affine::compose(&simplified, 10),
+            Affine::compose(&shuffles, 10)
+        );
+
+        assert_eq!(simplify(&[Shuffle::Cut(10)], 10).unwrap(), []);
+        assert!(simplify(&[Shuffle::DealWithIncrement(5)], 10).is_err());
+
+        // A deck without cards can't be shuffled, with a single card nothing changes.
+        assert!(simplify(&[], 0).is_err());
+        assert_eq!(simplify(&shuffles, 1).unwrap(), []);
+        assert_eq!(Shuffle::DealWithIncrement(3).invert(0), None);
+    }
+
+    fn shuffles() -> impl Strategy<Value = Vec<Shuffle>> {
+        let shuffle = prop_oneof![
+            Just(Shuffle::DealIntoNewStack),
+            (-200isize..200).prop_map(Shuffle::Cut),
+            (1usize..200).prop_map(Shuffle::DealWithIncrement),
+        ];
+        prop::collection::vec(shuffle, 0..20)
+    }
+
+    proptest! {
+        #[test]
+        fn models_agree_with_deck(shuffles in shuffles(), deck_size in 1usize..150) {
+            let mut deck = Deck::new(deck_size);
+            let dealt = shuffles.iter().try_for_each(|shuffle| deck.shuffle(shuffle));
+            let affine = Affine::compose(&shuffles, deck_size as u64);
+            let inverted: Option<Vec<Shuffle>> =
+                shuffles.iter().rev().map(|s| s.invert(deck_size)).collect();
+
+            // Sizes with common factors with an increment can't be dealt, nor undone.
+            if dealt.is_err() {
+                prop_assert!(inverted.is_none());
+                prop_assert!(affine.inverse().is_none());
+                return Ok(());
+            }
+
+            let inverted = inverted.unwrap();
+            let inverse = affine.inverse().unwrap();
+            for card in 0..deck_size {
+                let position = shuffles
+                    .iter()
+                    .fold(card, |p, s| s.track_position(deck_size, p));
+                prop_assert_eq!(deck.position(card as u32), Some(position));
+                prop_assert_eq!(affine.apply(card as u64), position as u64);
+
+                let undone = inverted
+                    .iter()
+                    .fold(position, |p, s| s.track_position(deck_size, p));
+                prop_assert_eq!(undone, card);
+                prop_assert_eq!(inverse.apply(position as u64), card as u64);
+            }
+        }
+
+        #[test]
+        fn pow_agrees_with_deck(shuffles in shuffles(), deck_size in 1usize..50, times in 0u64..10) {
+            let mut deck = Deck::new(deck_size);
+            let dealt = (0..times)
+                .try_for_each(|_| shuffles.iter().try_for_each(|shuffle| deck.shuffle(shuffle)));
+            prop_assume!(dealt.is_ok());
+
+            let affine = Affine::compose(&shuffles, deck_size as u64).pow(times);
+            for card in 0..deck_size {
+                prop_assert_eq!(deck.position(card as u32), Some(affine.apply(card as u64) as usize));
+            }
+        }
+
+        #[test]
+        fn simplify_is_equivalent(shuffles in shuffles(), deck_size in 1usize..10_000) {
+            let affine = Affine::compose(&shuffles, deck_size as u64);
+            match simplify(&shuffles, deck_size) {
+                Ok(simplified) => {
+                    prop_assert!(simplified.len() <= 3);
+                    prop_assert_eq!(Affine::compose(&simplified, deck_size as u64), affine);
+                    let text: Vec<String> = simplified.iter().map(|s| s.to_string()).collect();
+                    let parsed: Vec<Shuffle> = text.iter().map(|s| s.parse().unwrap()).collect();
+                    prop_assert_eq!(parsed, simplified);
+                }
+                Err(_) => prop_assert!(affine.inverse().is_none()),
+            }
+        }
+
+        #[test]
+        fn display_roundtrip(shuffles in shuffles()) {
+            for shuffle in shuffles {
+                prop_assert_eq!(shuffle.to_string().parse::<Shuffle>().unwrap(), shuffle);
+            }
+        }
+    }
+}
@@ -1,54 +1,36 @@
 use std::collections::{HashMap, HashSet};
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 use std::str::FromStr;
 
-use crate::Resource::{Clay, Geode, Obsidian, Ore};
+use self::Resource::{Clay, Geode, Obsidian, Ore};
+use crate::log;
+use crate::parse::{self, regex_struct};
+use crate::solution::{Answer, Solution};
 use anyhow::Result;
-use lazy_static::lazy_static;
 use rayon::prelude::*;
-use regex::Regex;
 
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-    let max_time = match args.get(1) {
-        None => 24,
-        Some(arg) => arg.parse::<i32>()?,
-    };
-
-    println!("Number of minutes to simulate: {max_time}");
-
-    let path = env::current_dir()?.join("src/bin/y2022d19/input.txt");
-    let reader = BufReader::new(File::open(path)?);
+pub struct Day;
 
-    let mut blueprints: Vec<Blueprint> = Vec::new();
+impl Solution for Day {
+    type Input = Vec<Blueprint>;
 
-    for line in reader.lines() {
-        let line = line?;
-
-        blueprints.push(line.parse()?)
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, str::parse)
     }
 
-    let mut total = 0;
+    fn part1(blueprints: &Self::Input) -> Result<Answer> {
+        let max_time = 24;
 
-    let results: Vec<_> = blueprints
-        .par_iter()
-        .map(|bp| {
-            let max_geodes = maximize_geodes(max_time, bp);
-            println!("{} geodes for {:?}", max_geodes, bp.id);
-            (bp, max_geodes)
-        })
-        .collect();
+        let total: i32 = blueprints
+            .par_iter()
+            .map(|bp| {
+                let max_geodes = maximize_geodes(max_time, bp);
+                log::debug!("{} geodes for {:?}", max_geodes, bp.id);
+                bp.id * max_geodes
+            })
+            .sum();
 
-    for (bp, max_geodes) in results {
-        total += bp.id * max_geodes;
+        Ok(Some(total.to_string()))
     }
-
-    println!("Total quality: {total}");
-
-    Ok(())
 }
 
 fn maximize_geodes(time_remaining: i32, blueprint: &Blueprint) -> i32 {
@@ -205,54 +187,63 @@ struct Robot {
 }
 
 #[derive(Debug)]
-struct Blueprint {
+pub struct Blueprint {
     id: i32,
     robots: Vec<Robot>,
 }
 
-lazy_static! {
-    static ref BP_RE: Regex = Regex::new(r"^Blueprint (?<id>\d+): Each ore robot costs (?<oo>\d+) ore. Each clay robot costs (?<co>\d+) ore. Each obsidian robot costs (?<bo>\d+) ore and (?<bc>\d+) clay. Each geode robot costs (?<go>\d+) ore and (?<gb>\d+) obsidian.$").unwrap();
+regex_struct! {
+    #[regex = r"^Blueprint (?<id>\d+): Each ore robot costs (?<ore_ore>\d+) ore. Each clay robot costs (?<clay_ore>\d+) ore. Each obsidian robot costs (?<obsidian_ore>\d+) ore and (?<obsidian_clay>\d+) clay. Each geode robot costs (?<geode_ore>\d+) ore and (?<geode_obsidian>\d+) obsidian.$"]
+    /// Costs as listed in the input, named after the robot and the resource.
+    struct Costs {
+        id: i32,
+        ore_ore: i32,
+        clay_ore: i32,
+        obsidian_ore: i32,
+        obsidian_clay: i32,
+        geode_ore: i32,
+        geode_obsidian: i32,
+    }
 }
 
 impl FromStr for Blueprint {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let captures = BP_RE
-            .captures(s)
-            .ok_or(anyhow::anyhow!("Unable to match {}", s))?;
-
-        let id = captures["id"].parse()?;
+        let costs: Costs = s.parse()?;
         let robots = vec![
             Robot {
                 produces: Ore,
-                cost_ore: captures["oo"].parse()?,
+                cost_ore: costs.ore_ore,
                 cost_clay: 0,
                 cost_obsidian: 0,
             },
             Robot {
                 produces: Clay,
-                cost_ore: captures["co"].parse()?,
+                cost_ore: costs.clay_ore,
                 cost_clay: 0,
                 cost_obsidian: 0,
             },
             Robot {
                 produces: Obsidian,
-                cost_ore: captures["bo"].parse()?,
-                cost_clay: captures["bc"].parse()?,
+                cost_ore: costs.obsidian_ore,
+                cost_clay: costs.obsidian_clay,
                 cost_obsidian: 0,
             },
             Robot {
                 produces: Geode,
-                cost_ore: captures["go"].parse()?,
+                cost_ore: costs.geode_ore,
                 cost_clay: 0,
-                cost_obsidian: captures["gb"].parse()?,
+                cost_obsidian: costs.geode_obsidian,
             },
         ];
 
         // "High-tech" ones in front, for better branch pruning
         let robots = robots.into_iter().rev().collect();
 
-        Ok(Blueprint { id, robots })
+        Ok(Blueprint {
+            id: costs.id,
+            robots,
+        })
     }
 }
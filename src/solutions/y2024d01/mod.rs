@@ -0,0 +1,52 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::str::FromStr;
+
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    /// The left and right lists, both sorted.
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let pairs = parse::lines(input, |line| {
+            let (left, right) = line
+                .split_whitespace()
+                .collect_tuple()
+                .ok_or(anyhow!("Expected two numbers"))?;
+            Ok((i32::from_str(left)?, i32::from_str(right)?))
+        })?;
+
+        let (mut left, mut right): (Vec<i32>, Vec<i32>) = pairs.into_iter().unzip();
+        left.sort();
+        right.sort();
+
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input) -> Result<Answer> {
+        let total: i32 = left
+            .iter()
+            .zip(right.iter())
+            .map(|(a, b)| (*a - *b).abs())
+            .sum();
+
+        Ok(Some(total.to_string()))
+    }
+
+    fn part2((left, right): &Self::Input) -> Result<Answer> {
+        let counts = right.iter().counts();
+        let similarity: i32 = left
+            .iter()
+            .map(|n| {
+                let count = counts.get(n).unwrap_or(&0);
+                *n * (*count as i32)
+            })
+            .sum();
+
+        Ok(Some(similarity.to_string()))
+    }
+}
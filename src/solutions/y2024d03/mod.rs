@@ -0,0 +1,30 @@
+use anyhow::Result;
+
+use crate::parse;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    /// Operands of the valid `mul` instructions.
+    type Input = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let re = regex::Regex::new(r"mul\((\d+),(\d+)\)")?;
+        let lines = parse::lines(input, |line| {
+            re.captures_iter(line)
+                .map(|c| {
+                    let (_, [a, b]) = c.extract();
+                    Ok((a.parse()?, b.parse()?))
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        Ok(lines.concat())
+    }
+
+    fn part1(muls: &Self::Input) -> Result<Answer> {
+        let result: usize = muls.iter().map(|(a, b)| a * b).sum();
+        Ok(Some(result.to_string()))
+    }
+}
@@ -0,0 +1,85 @@
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::grid::{Grid, Pos};
+use crate::log;
+use crate::math::gcd;
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = City;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map = Grid::parse(input, Ok)?;
+
+        let mut antennas: HashMap<char, Vec<(i64, i64)>> = HashMap::new();
+        for ((row, col), &c) in map.iter() {
+            if c != '.' {
+                antennas
+                    .entry(c)
+                    .or_default()
+                    .push((row as i64, col as i64));
+            }
+        }
+
+        log::debug!("{:?}", antennas);
+
+        Ok(City { map, antennas })
+    }
+
+    fn part1(city: &Self::Input) -> Result<Answer> {
+        Ok(Some(antinodes(city).to_string()))
+    }
+
+    fn part2(city: &Self::Input) -> Result<Answer> {
+        Ok(Some(resonant_antinodes(city).to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub struct City {
+    map: Grid<char>,
+    /// Antenna positions by frequency.
+    antennas: HashMap<char, Vec<(i64, i64)>>,
+}
+
+fn antinodes(city: &City) -> usize {
+    let mut antinodes: HashSet<Pos> = HashSet::new();
+
+    for (_, positions) in city.antennas.iter() {
+        positions.iter().tuple_combinations().for_each(|(a, b)| {
+            let first = city.map.checked_pos(2 * a.0 - b.0, 2 * a.1 - b.1);
+            let second = city.map.checked_pos(2 * b.0 - a.0, 2 * b.1 - a.1);
+            antinodes.extend(first.into_iter().chain(second));
+        });
+    }
+
+    log::debug!("{:?}", antinodes);
+    antinodes.len()
+}
+
+fn resonant_antinodes(city: &City) -> usize {
+    let size = city.map.width().max(city.map.height()) as i64;
+
+    let mut antinodes: HashSet<Pos> = HashSet::new();
+    for (_, positions) in city.antennas.iter() {
+        positions.iter().tuple_combinations().for_each(|(a, b)| {
+            let dx = b.0 - a.0;
+            let dy = b.1 - a.1;
+            let d = gcd(dx.abs(), dy.abs());
+            let dx = dx / d;
+            let dy = dy / d;
+
+            for i in 0..size {
+                let forward = city.map.checked_pos(b.0 + dx * i, b.1 + dy * i);
+                let backward = city.map.checked_pos(b.0 - dx * i, b.1 - dy * i);
+                antinodes.extend(forward.into_iter().chain(backward));
+            }
+        });
+    }
+
+    antinodes.len()
+}
@@ -0,0 +1,131 @@
+use anyhow::{anyhow, Result};
+use std::collections::{HashMap, HashSet};
+
+use crate::grid::{Grid, Pos};
+use crate::log;
+use crate::search::bfs;
+use crate::solution::{Answer, Solution};
+
+type Maze = Grid<Tile>;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Racetrack;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let maze = Grid::parse(input, |c| match c {
+            'S' => Ok(Tile::Start),
+            'E' => Ok(Tile::End),
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Floor),
+            _ => Err(anyhow!("Invalid tile {}", c)),
+        })?;
+
+        let start = maze
+            .find(|&t| t == Tile::Start)
+            .ok_or(anyhow!("Missing start"))?;
+        let end = maze
+            .find(|&t| t == Tile::End)
+            .ok_or(anyhow!("Missing end"))?;
+
+        Ok(Racetrack { maze, start, end })
+    }
+
+    fn part1(racetrack: &Self::Input) -> Result<Answer> {
+        Ok(Some(good_cheats(racetrack)?.to_string()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Racetrack {
+    maze: Maze,
+    start: Pos,
+    end: Pos,
+}
+
+/// Number of cheats through a single wall that save at least 100 picoseconds.
+fn good_cheats(racetrack: &Racetrack) -> Result<usize> {
+    let Racetrack { maze, start, end } = racetrack;
+    let (start, end) = (*start, *end);
+
+    let distances_from_start = distances_from(start, maze);
+    log::debug!("Start: {:?}", start);
+
+    let distances_from_end = distances_from(end, maze);
+    log::debug!("End: {:?}", end);
+
+    let start_to_end = *distances_from_start
+        .get(&end)
+        .ok_or(anyhow!("End is not reachable from start"))?;
+
+    log::debug!("Start to end: {}", start_to_end);
+
+    let mut good_cheats = HashSet::new();
+
+    distances_from_start
+        .iter()
+        .for_each(|(pos, distance_from_start)| {
+            for neighbor in maze.neighbours4(*pos) {
+                if maze[neighbor] != Tile::Wall {
+                    continue;
+                }
+
+                for neighbor2 in maze.neighbours4(neighbor) {
+                    if maze[neighbor2] == Tile::Wall {
+                        continue;
+                    }
+
+                    // Floor cut off from the end can't lead to a shorter race.
+                    let Some(distance_from_end) = distances_from_end.get(&neighbor2) else {
+                        continue;
+                    };
+
+                    let new_start_to_end = distance_from_start + 2 + distance_from_end;
+
+                    if new_start_to_end >= start_to_end {
+                        continue;
+                    }
+
+                    let saved = start_to_end - new_start_to_end;
+
+                    if saved >= 100 {
+                        good_cheats.insert((pos, neighbor2));
+                    }
+                }
+            }
+        });
+
+    Ok(good_cheats.len())
+}
+
+fn distances_from(start: Pos, maze: &Maze) -> HashMap<Pos, usize> {
+    bfs(
+        start,
+        |&pos| {
+            maze.neighbours4(pos)
+                .filter(|&next| maze[next] != Tile::Wall)
+        },
+        |_| false,
+    )
+    .into_distances()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Tile {
+    Start,
+    End,
+    Wall,
+    Floor,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unreachable_end() {
+        let racetrack = Day::parse("#####\n#S#E#\n#####").unwrap();
+        assert!(good_cheats(&racetrack).is_err());
+    }
+}
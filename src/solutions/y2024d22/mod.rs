@@ -1,20 +1,31 @@
 use anyhow::Result;
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::env;
-use std::fs::File;
-use std::io::BufRead;
-use std::io::BufReader;
 
-fn main() -> Result<()> {
-    let path = env::current_dir()?.join("src/bin/y2024d22/input.txt");
-    let reader = BufReader::new(File::open(path)?);
+use crate::parse;
+use crate::solution::{Answer, Solution};
 
+pub struct Day;
+
+impl Solution for Day {
+    /// Initial secret numbers of the buyers.
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse::lines(input, |l| Ok(l.parse()?))
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Answer> {
+        Ok(best_sequence(numbers).map(|bananas| bananas.to_string()))
+    }
+}
+
+/// Most bananas that can be bought with a single sequence of four price changes.
+fn best_sequence(numbers: &[u64]) -> Option<i64> {
     let mut sequences: Vec<Vec<u64>> = Vec::new();
 
-    for line in reader.lines() {
-        let line = line?;
-        let mut num: u64 = line.parse()?;
+    for &num in numbers {
+        let mut num = num;
         let mut sequence = Vec::new();
         sequence.push(num);
 
@@ -61,20 +72,18 @@ fn main() -> Result<()> {
                 })
         });
 
-    let max_score = pattern_scores.values().max().unwrap();
-    println!("Max score: {}", max_score);
-
-    Ok(())
+    pattern_scores.values().max().copied()
 }
 
 fn next_number(num: u64) -> u64 {
     let base = 16777216;
     let num = (num ^ (num * 64)) % base;
     let num = (num ^ (num / 32)) % base;
-    
+
     (num ^ (num * 2048)) % base
 }
 
+#[cfg(test)]
 fn repeated_next_number(num: u64, n: usize) -> u64 {
     let mut num = num;
     for _ in 0..n {
@@ -0,0 +1,73 @@
+use anyhow::Result;
+
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use crate::solution::{Answer, Solution};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Schematics;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut schematics = Schematics::default();
+
+        let mut offset = 0;
+        for text in input.trim_end().split("\n\n") {
+            let first_line = text.lines().next().unwrap_or_default();
+            let at_block = |message: String| ParseError::at_line(offset + 1, first_line, message);
+
+            let block = Grid::parse(text, Ok).map_err(|e| parse::offset_lines(e, offset))?;
+            if (block.width(), block.height()) != (5, 7) {
+                let message = format!("Expected a 5x7 schematic, got\n{}", block);
+                return Err(at_block(message).into());
+            }
+
+            let is_filled = |row: usize| (0..5).all(|col| block[(row, col)] == '#');
+            if is_filled(0) {
+                schematics.locks.push(count_pins(&block));
+            } else if is_filled(6) {
+                schematics.keys.push(count_pins(&block));
+            } else {
+                let message = format!("Schematic is neither a lock nor a key\n{}", block);
+                return Err(at_block(message).into());
+            }
+
+            offset += text.lines().count() + 1;
+        }
+
+        Ok(schematics)
+    }
+
+    fn part1(schematics: &Self::Input) -> Result<Answer> {
+        let mut pair_count = 0;
+
+        for lock in &schematics.locks {
+            for key in &schematics.keys {
+                if lock.iter().zip(key.iter()).all(|(l, k)| l + k <= 5) {
+                    pair_count += 1;
+                }
+            }
+        }
+
+        Ok(Some(pair_count.to_string()))
+    }
+}
+
+/// Pin heights of the locks and keys.
+#[derive(Debug, Default)]
+pub struct Schematics {
+    locks: Vec<[u8; 5]>,
+    keys: Vec<[u8; 5]>,
+}
+
+/// Height of each pin, not counting the filled base row.
+fn count_pins(block: &Grid<char>) -> [u8; 5] {
+    let mut pins = [0; 5];
+
+    for (pin, column) in pins.iter_mut().zip(block.transpose().rows()) {
+        *pin = column.iter().filter(|&&c| c == '#').count() as u8 - 1;
+    }
+
+    pins
+}
@@ -0,0 +1,33 @@
+//! Runs every solution against the examples from the puzzle descriptions. Each puzzle can have
+//! an `examples` directory with `<name>.txt` inputs and `<name>.answers.txt` expected answers,
+//! and a test is generated for every part with a known answer.
+
+use std::fs;
+
+use aoc::{answers, input, registry};
+
+fn check(year: u16, day: u8, example: &str, part: u8) {
+    let puzzle = registry::find(year, day).expect("Puzzle is not registered");
+    let path = input::puzzle_dir(puzzle)
+        .join("examples")
+        .join(format!("{}.txt", example));
+
+    let input = fs::read_to_string(&path).unwrap();
+    let expected = answers::load(&path).unwrap().unwrap();
+    let input = puzzle.parse(&input).unwrap();
+
+    let (expected, actual) = match part {
+        1 => (expected.part1, puzzle.part1(&input).unwrap()),
+        _ => (expected.part2, puzzle.part2(&input).unwrap()),
+    };
+    assert_eq!(
+        actual,
+        expected,
+        "{} example {} part {}",
+        puzzle.name(),
+        example,
+        part
+    );
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));